@@ -0,0 +1,12 @@
+/// Example printing displays as they are connected or disconnected
+use libmonitor::{MonitorEvent, MonitorWatcher};
+
+fn main() {
+    for event in MonitorWatcher::new().expect("failed to watch udev events") {
+        match event {
+            MonitorEvent::Added(monitor) => println!("added: {monitor:#}"),
+            MonitorEvent::Changed(monitor) => println!("changed: {monitor:#}"),
+            MonitorEvent::Removed { name, info } => println!("removed: {name} ({})", info.vendor()),
+        }
+    }
+}
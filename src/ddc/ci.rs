@@ -1,4 +1,8 @@
-use nom::{combinator::peek, number::complete::le_u8};
+use nom::{
+    bytes::complete::take,
+    combinator::peek,
+    number::complete::{be_u16, le_u8},
+};
 use nom::{IResult, Parser};
 use thiserror::Error;
 
@@ -14,6 +18,14 @@ pub enum DdcCiProtocolError {
     InvalidMessageFormat,
     #[error("Error parsing DDC CI Message: {0}")]
     ParserError(String),
+    #[error("table offset exceeds the 16 bit offset range")]
+    OffsetOutOfRange,
+    #[error("reply for table offset {received:#x} does not match requested offset {expected:#x}")]
+    UnexpectedOffset { expected: u16, received: u16 },
+    #[error(
+        "reply for vcp feature {received:#04x} does not match requested feature {expected:#04x}"
+    )]
+    UnexpectedVcpFeature { expected: u8, received: u8 },
 }
 
 const DDC_SLAVE_SEND_ADDR: u8 = 0x6f;
@@ -22,6 +34,9 @@ const DDC_MASTER_SEND_ADDR: u8 = 0x51;
 const DDC_MASTER_RECV_ADDR: u8 = 0x50;
 
 const LENGTH_PREFIX: u8 = 0x80;
+// timing replies do not use a length field, the timing reply opcode is followed by this message opcode
+const TIMING_MESSAGE_OPCODE: u8 = 0x4e;
+const TIMING_REPORT_LENGTH: u8 = 5;
 
 pub const DDC_MAX_DATA_FRAGMENT_LENGTH: usize = 32;
 // when receiving unknown opcodes they may or maynot have additional offset or vcp information that
@@ -204,7 +219,7 @@ fn parse_result_code(i: &[u8]) -> IResult<&[u8], ResultCode> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum VcpType {
     SetParameter,
     Momentary,
@@ -248,6 +263,24 @@ impl FeatureReplyMessage {
     }
 }
 
+/// Untyped VCP feature value as reported by the display
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RawVcpValue {
+    pub vcp_type: VcpType,
+    pub max: u16,
+    pub val: u16,
+}
+
+impl From<&FeatureReplyMessage> for RawVcpValue {
+    fn from(value: &FeatureReplyMessage) -> Self {
+        Self {
+            vcp_type: value.type_code,
+            max: (value.vcp_data >> 16) as u16,
+            val: (value.vcp_data & 0xffff) as u16,
+        }
+    }
+}
+
 pub fn parse_feature_reply(i: &[u8]) -> IResult<&[u8], FeatureReplyMessage> {
     let (i, rc) = parse_result_code(i)?;
     let (i, vcp_code) = le_u8(i)?;
@@ -267,6 +300,83 @@ pub fn parse_feature_reply(i: &[u8]) -> IResult<&[u8], FeatureReplyMessage> {
     ))
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyncPolarity {
+    Positive,
+    Negative,
+}
+
+/// Timing information reported by the display for the currently received signal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimingReport {
+    sync_out_of_range: bool,
+    unstable_count: bool,
+    horizontal_sync_polarity: SyncPolarity,
+    vertical_sync_polarity: SyncPolarity,
+    horizontal_frequency: u16,
+    vertical_frequency: u16,
+}
+
+impl TimingReport {
+    /// the display reports that the sync frequency of the signal is out of its supported range
+    pub fn is_out_of_range(&self) -> bool {
+        self.sync_out_of_range
+    }
+
+    /// the display was not able to get a stable count of the input signal, this usually
+    /// means there is no signal present at the active input
+    pub fn is_unstable(&self) -> bool {
+        self.unstable_count
+    }
+
+    /// check if the display is receiving a usable signal
+    pub fn has_signal(&self) -> bool {
+        !self.sync_out_of_range && !self.unstable_count
+    }
+
+    pub fn horizontal_sync_polarity(&self) -> SyncPolarity {
+        self.horizontal_sync_polarity
+    }
+
+    pub fn vertical_sync_polarity(&self) -> SyncPolarity {
+        self.vertical_sync_polarity
+    }
+
+    /// horizontal frequency in Hz, the display reports this value with a resolution of 10 Hz
+    pub fn horizontal_frequency(&self) -> u32 {
+        self.horizontal_frequency as u32 * 10
+    }
+
+    /// vertical frequency in Hz, the display reports this value with a resolution of 0.01 Hz
+    pub fn vertical_frequency(&self) -> f64 {
+        self.vertical_frequency as f64 / 100.
+    }
+}
+
+pub fn parse_timing_report(i: &[u8]) -> IResult<&[u8], TimingReport> {
+    let (i, status) = le_u8(i)?;
+    let (i, horizontal_frequency) = be_u16(i)?;
+    let (i, vertical_frequency) = be_u16(i)?;
+    let polarity = |bit: bool| {
+        if bit {
+            SyncPolarity::Positive
+        } else {
+            SyncPolarity::Negative
+        }
+    };
+    Ok((
+        i,
+        TimingReport {
+            sync_out_of_range: status & 0x80 != 0,
+            unstable_count: status & 0x40 != 0,
+            horizontal_sync_polarity: polarity(status & 0x02 != 0),
+            vertical_sync_polarity: polarity(status & 0x01 != 0),
+            horizontal_frequency,
+            vertical_frequency,
+        },
+    ))
+}
+
 #[derive(Debug, PartialEq)]
 pub struct DdcCiMessage {
     target: u8,
@@ -300,9 +410,14 @@ impl DdcCiMessage {
             self.target
         };
         checksum ^= self.sender;
-        checksum ^= LENGTH_PREFIX | self.protocol_length();
-        if let Some(opcode) = &self.opcode {
-            checksum ^= Into::<u8>::into(opcode);
+        if self.is_timing_reply() {
+            checksum ^= Into::<u8>::into(&DdcOpcode::TimingReply);
+            checksum ^= TIMING_MESSAGE_OPCODE;
+        } else {
+            checksum ^= LENGTH_PREFIX | self.protocol_length();
+            if let Some(opcode) = &self.opcode {
+                checksum ^= Into::<u8>::into(opcode);
+            }
         }
         if let Some(vcp_feature) = &self.vcp_feature {
             checksum ^= Into::<u8>::into(*vcp_feature);
@@ -354,6 +469,11 @@ impl DdcCiMessage {
         self.opcode.as_ref()
     }
 
+    /// timing replies have their own message format without a length field
+    fn is_timing_reply(&self) -> bool {
+        self.opcode == Some(DdcOpcode::TimingReply)
+    }
+
     pub fn set_vcp_feature(mut self, feature: VcpFeatureCode) -> Self {
         self.vcp_feature = Some(feature);
         self
@@ -382,9 +502,7 @@ impl DdcCiMessage {
             return Err(DdcCiProtocolError::InvalidLength);
         }
         self.data_length = data.len() as u8;
-        for i in 0..data.len() {
-            self.data[i] = data[i];
-        }
+        self.data[..data.len()].copy_from_slice(data);
         Ok(self)
     }
 
@@ -404,9 +522,14 @@ impl DdcCiMessage {
         // sender field is not part of protocol length so we need one extra byte here
         let mut data = Vec::with_capacity((self.protocol_length() + 1).into());
         data.push(self.sender);
-        data.push(LENGTH_PREFIX | self.protocol_length());
-        if let Some(opcode) = &self.opcode {
-            data.push(Into::<u8>::into(opcode));
+        if self.is_timing_reply() {
+            data.push(Into::<u8>::into(&DdcOpcode::TimingReply));
+            data.push(TIMING_MESSAGE_OPCODE);
+        } else {
+            data.push(LENGTH_PREFIX | self.protocol_length());
+            if let Some(opcode) = &self.opcode {
+                data.push(Into::<u8>::into(opcode));
+            }
         }
         if let Some(vcp_feature) = &self.vcp_feature {
             data.push(Into::<u8>::into(*vcp_feature));
@@ -479,7 +602,30 @@ impl DdcCiMessage {
                 Err(DdcCiProtocolError::InvalidChecksum)
             }
         } else if maybe_length == (&DdcOpcode::TimingReply).into() {
-            todo!()
+            let (i, _opcode) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?;
+            let (i, timing_opcode) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?;
+            if timing_opcode != TIMING_MESSAGE_OPCODE {
+                return Err(DdcCiProtocolError::InvalidMessageFormat);
+            }
+            let mut message = Self {
+                target,
+                sender,
+                opcode: Some(DdcOpcode::TimingReply),
+                vcp_feature: None,
+                offset: None,
+                data_length: TIMING_REPORT_LENGTH,
+                data: [0; DDC_MAX_DATA_FRAGMENT_LENGTH_WITH_EXTRA],
+            };
+            let (i, report) =
+                take::<usize, &[u8], nom::error::Error<_>>(TIMING_REPORT_LENGTH as usize)
+                    .parse(i)?;
+            message.data[..report.len()].copy_from_slice(report);
+            let (_i, check_sum) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?;
+            if check_sum == message.compute_checksum() {
+                Ok(message)
+            } else {
+                Err(DdcCiProtocolError::InvalidChecksum)
+            }
         } else {
             Err(DdcCiProtocolError::InvalidMessageFormat)
         }
@@ -499,7 +645,7 @@ where
 mod test {
     use crate::ddc::ci::{DDC_SLAVE_RECV_ADDR, DDC_SLAVE_SEND_ADDR};
 
-    use super::DdcCiMessage;
+    use super::{parse_timing_report, DdcCiMessage, DdcOpcode, SyncPolarity};
 
     struct TestCiMessage {
         data: Vec<u8>,
@@ -548,4 +694,44 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn parse_timing_reply() {
+        let data = [
+            DDC_SLAVE_SEND_ADDR,
+            DDC_SLAVE_RECV_ADDR,
+            0x06,
+            0x4e,
+            0x03,
+            0x1c,
+            0xe8,
+            0x17,
+            0x6b,
+            0xfd,
+        ];
+        let recv_msg = DdcCiMessage::parse_buffer(&data).unwrap();
+        assert_eq!(recv_msg.get_opcode(), Some(&DdcOpcode::TimingReply));
+        assert_eq!(recv_msg.transmit_buffer(), data[1..]);
+
+        let (_, report) = parse_timing_report(recv_msg.get_data()).unwrap();
+        assert!(report.has_signal());
+        assert_eq!(report.horizontal_frequency(), 74000);
+        assert_eq!(report.vertical_frequency(), 59.95);
+        assert_eq!(report.horizontal_sync_polarity(), SyncPolarity::Positive);
+        assert_eq!(report.vertical_sync_polarity(), SyncPolarity::Positive);
+    }
+
+    #[test]
+    fn parse_table_read_reply() {
+        let table_msg = DdcCiMessage::from_opcode(DdcOpcode::TableReadReply)
+            .set_offset(0x20)
+            .set_data(&[0x01, 0x02, 0x03, 0x04])
+            .unwrap();
+        let test = TestCiMessage::response(table_msg.addr(), &table_msg.transmit_buffer());
+
+        let recv_msg = DdcCiMessage::parse_buffer(&test.data).unwrap();
+        assert_eq!(recv_msg.get_offset(), Some(0x20));
+        assert_eq!(recv_msg.get_data(), &[0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(recv_msg, table_msg);
+    }
 }
@@ -0,0 +1,516 @@
+//! DisplayID
+//!
+//! Parsing of VESA DisplayID 1.x and 2.x structures, either read directly from the display or
+//! embedded as an edid extension block
+
+use nom::bytes::complete::take;
+use nom::number::complete::{le_u16, le_u32, le_u8};
+use nom::{IResult, Parser};
+use thiserror::Error;
+
+/// tag of edid extension blocks containing a DisplayID section
+pub const DISPLAY_ID_EXTENSION_TAG: u8 = 0x70;
+
+const SECTION_HEADER_LENGTH: usize = 4;
+/// type I and type VII detailed timings share the same layout
+const DETAILED_TIMING_LENGTH: usize = 20;
+const ADAPTIVE_SYNC_DESCRIPTOR_LENGTH: usize = 6;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DisplayId {
+    pub version: u8,
+    pub revision: u8,
+    /// display product type for version 1.x, primary use case for version 2.x
+    pub product_type: u8,
+    pub extension_count: u8,
+    pub data_blocks: Vec<DataBlock>,
+}
+
+impl DisplayId {
+    pub fn product_identification(&self) -> Option<&ProductIdentification> {
+        self.data_blocks.iter().find_map(|block| match block {
+            DataBlock::ProductIdentification(product) => Some(product),
+            _ => None,
+        })
+    }
+
+    pub fn tiled_topology(&self) -> Option<&TiledTopology> {
+        self.data_blocks.iter().find_map(|block| match block {
+            DataBlock::TiledTopology(topology) => Some(topology),
+            _ => None,
+        })
+    }
+
+    /// detailed timings of DisplayID 1.x structures
+    pub fn type_i_timings(&self) -> Vec<&TypeVIITiming> {
+        self.data_blocks
+            .iter()
+            .filter_map(|block| match block {
+                DataBlock::TypeITimings(timings) => Some(timings.iter()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub fn type_vii_timings(&self) -> Vec<&TypeVIITiming> {
+        self.data_blocks
+            .iter()
+            .filter_map(|block| match block {
+                DataBlock::TypeVIITimings(timings) => Some(timings.iter()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub fn adaptive_sync_ranges(&self) -> Vec<&AdaptiveSyncRange> {
+        self.data_blocks
+            .iter()
+            .filter_map(|block| match block {
+                DataBlock::AdaptiveSync(ranges) => Some(ranges.iter()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DataBlock {
+    ProductIdentification(ProductIdentification),
+    TiledTopology(TiledTopology),
+    /// DisplayID 1.x detailed timings, using the type VII layout with a coarser pixel clock
+    TypeITimings(Vec<TypeVIITiming>),
+    TypeVIITimings(Vec<TypeVIITiming>),
+    AdaptiveSync(Vec<AdaptiveSyncRange>),
+    Unknown {
+        tag: u8,
+        revision: u8,
+        payload: Vec<u8>,
+    },
+}
+
+/// DisplayID 1.x identifies manufacturers by their PNP id, DisplayID 2.x by their IEEE OUI
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ManufacturerId {
+    Pnp([char; 3]),
+    Oui(u32),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProductIdentification {
+    pub manufacturer: ManufacturerId,
+    pub product: u16,
+    pub serial: u32,
+    pub week: u8,
+    /// Starting at year 2000
+    pub year: u8,
+    pub product_name: String,
+}
+
+fn parse_manufacturer_id(id: &[u8], version: u8) -> ManufacturerId {
+    if version >= 2 {
+        ManufacturerId::Oui(u32::from_be_bytes([0, id[0], id[1], id[2]]))
+    } else {
+        ManufacturerId::Pnp([id[0] as char, id[1] as char, id[2] as char])
+    }
+}
+
+fn parse_product_identification(i: &[u8], version: u8) -> IResult<&[u8], ProductIdentification> {
+    let (i, manufacturer) = take(3_usize).parse(i)?;
+    let (i, product) = le_u16.parse(i)?;
+    let (i, serial) = le_u32.parse(i)?;
+    let (i, week) = le_u8.parse(i)?;
+    let (i, year) = le_u8.parse(i)?;
+    let (i, name_length) = le_u8.parse(i)?;
+    let (i, name) = take(name_length as usize).parse(i)?;
+    Ok((
+        i,
+        ProductIdentification {
+            manufacturer: parse_manufacturer_id(manufacturer, version),
+            product,
+            serial,
+            week,
+            year,
+            product_name: String::from_utf8_lossy(name).trim().to_string(),
+        },
+    ))
+}
+
+/// Layout of a single tile of a display that is driven by multiple video links
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct TiledTopology {
+    /// all tiles are contained in a single physical enclosure
+    pub single_enclosure: bool,
+    pub horizontal_tiles: u8,
+    pub vertical_tiles: u8,
+    /// horizontal position of this tile starting at 0 on the left
+    pub horizontal_location: u8,
+    /// vertical position of this tile starting at 0 at the top
+    pub vertical_location: u8,
+    pub tile_width: u16,
+    pub tile_height: u16,
+    /// bezel sizes in pixels (top, bottom, right, left)
+    pub bezel: Option<[f32; 4]>,
+    pub topology_vendor: ManufacturerId,
+    pub topology_product: u16,
+    pub topology_serial: u32,
+}
+
+fn parse_tiled_topology(i: &[u8], version: u8) -> IResult<&[u8], TiledTopology> {
+    let (i, capabilities) = le_u8.parse(i)?;
+    let (i, tiles_lo) = le_u8.parse(i)?;
+    let (i, location_lo) = le_u8.parse(i)?;
+    let (i, hi) = le_u8.parse(i)?;
+    let (i, tile_width) = le_u16.parse(i)?;
+    let (i, tile_height) = le_u16.parse(i)?;
+    let (i, pixel_multiplier) = le_u8.parse(i)?;
+    let (i, bezel) = take(4_usize).parse(i)?;
+    let (i, vendor) = take(3_usize).parse(i)?;
+    let (i, topology_product) = le_u16.parse(i)?;
+    let (i, topology_serial) = le_u32.parse(i)?;
+    // bezel information is only valid if the capabilities report it
+    let bezel = (capabilities & 0x40 != 0).then(|| {
+        let scale = |size: u8| size as f32 * pixel_multiplier as f32 / 10.0;
+        [
+            scale(bezel[0]),
+            scale(bezel[1]),
+            scale(bezel[2]),
+            scale(bezel[3]),
+        ]
+    });
+    Ok((
+        i,
+        TiledTopology {
+            single_enclosure: capabilities & 0x80 != 0,
+            horizontal_tiles: ((tiles_lo >> 4) | ((hi >> 6) << 4)) + 1,
+            vertical_tiles: ((tiles_lo & 0x0f) | (((hi >> 4) & 0x03) << 4)) + 1,
+            horizontal_location: (location_lo >> 4) | (((hi >> 2) & 0x03) << 4),
+            vertical_location: (location_lo & 0x0f) | ((hi & 0x03) << 4),
+            tile_width: tile_width.saturating_add(1),
+            tile_height: tile_height.saturating_add(1),
+            bezel,
+            topology_vendor: parse_manufacturer_id(vendor, version),
+            topology_product,
+            topology_serial,
+        },
+    ))
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct TypeVIITiming {
+    /// Pixel clock in kHz.
+    pub pixel_clock: u32,
+    pub preferred: bool,
+    pub interlaced: bool,
+    pub horizontal_active_pixels: u16,
+    pub horizontal_blanking_pixels: u16,
+    pub horizontal_front_porch: u16,
+    pub horizontal_sync_width: u16,
+    pub horizontal_sync_positive: bool,
+    pub vertical_active_lines: u16,
+    pub vertical_blanking_lines: u16,
+    pub vertical_front_porch: u16,
+    pub vertical_sync_width: u16,
+    pub vertical_sync_positive: bool,
+}
+
+impl TypeVIITiming {
+    /// vertical refresh rate in Hz
+    pub fn refresh_rate(&self) -> f64 {
+        let horizontal_total =
+            self.horizontal_active_pixels as f64 + self.horizontal_blanking_pixels as f64;
+        let vertical_total =
+            self.vertical_active_lines as f64 + self.vertical_blanking_lines as f64;
+        self.pixel_clock as f64 * 1000.0 / (horizontal_total * vertical_total)
+    }
+}
+
+/// parse a detailed timing, the pixel clock is given in units of `pixel_clock_unit` kHz
+fn parse_detailed_timing(i: &[u8], pixel_clock_unit: u32) -> IResult<&[u8], TypeVIITiming> {
+    let (i, pixel_clock) = take(3_usize).parse(i)?;
+    let (i, options) = le_u8.parse(i)?;
+    let (i, horizontal_active) = le_u16.parse(i)?;
+    let (i, horizontal_blanking) = le_u16.parse(i)?;
+    let (i, horizontal_front_porch) = le_u16.parse(i)?;
+    let (i, horizontal_sync_width) = le_u16.parse(i)?;
+    let (i, vertical_active) = le_u16.parse(i)?;
+    let (i, vertical_blanking) = le_u16.parse(i)?;
+    let (i, vertical_front_porch) = le_u16.parse(i)?;
+    let (i, vertical_sync_width) = le_u16.parse(i)?;
+    Ok((
+        i,
+        TypeVIITiming {
+            pixel_clock: (u32::from_le_bytes([pixel_clock[0], pixel_clock[1], pixel_clock[2], 0])
+                + 1)
+                * pixel_clock_unit,
+            preferred: options & 0x80 != 0,
+            interlaced: options & 0x10 != 0,
+            horizontal_active_pixels: horizontal_active.saturating_add(1),
+            horizontal_blanking_pixels: horizontal_blanking.saturating_add(1),
+            horizontal_front_porch: (horizontal_front_porch & 0x7fff) + 1,
+            horizontal_sync_width: horizontal_sync_width.saturating_add(1),
+            horizontal_sync_positive: horizontal_front_porch & 0x8000 != 0,
+            vertical_active_lines: vertical_active.saturating_add(1),
+            vertical_blanking_lines: vertical_blanking.saturating_add(1),
+            vertical_front_porch: (vertical_front_porch & 0x7fff) + 1,
+            vertical_sync_width: vertical_sync_width.saturating_add(1),
+            vertical_sync_positive: vertical_front_porch & 0x8000 != 0,
+        },
+    ))
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct AdaptiveSyncRange {
+    /// range applies to the native panel timing
+    pub native: bool,
+    /// minimum refresh rate in Hz
+    pub min_refresh_rate: u16,
+    /// maximum refresh rate in Hz
+    pub max_refresh_rate: u16,
+}
+
+fn parse_adaptive_sync_range(i: &[u8]) -> IResult<&[u8], AdaptiveSyncRange> {
+    let (i, flags) = le_u8.parse(i)?;
+    let (i, _max_duration_increase) = le_u8.parse(i)?;
+    let (i, min_refresh_rate) = le_u8.parse(i)?;
+    let (i, max_refresh_rate) = le_u16.parse(i)?;
+    let (i, _max_duration_decrease) = le_u8.parse(i)?;
+    Ok((
+        i,
+        AdaptiveSyncRange {
+            native: flags & 0x01 != 0,
+            min_refresh_rate: min_refresh_rate as u16,
+            max_refresh_rate: (max_refresh_rate & 0x03ff) + 1,
+        },
+    ))
+}
+
+fn parse_data_block(i: &[u8], version: u8) -> IResult<&[u8], DataBlock> {
+    let (i, tag) = le_u8.parse(i)?;
+    let (i, revision) = le_u8.parse(i)?;
+    let (i, length) = le_u8.parse(i)?;
+    let (i, payload) = take(length as usize).parse(i)?;
+    let block = match (version, tag) {
+        (1, 0x00) | (2, 0x20) => {
+            DataBlock::ProductIdentification(parse_product_identification(payload, version)?.1)
+        }
+        (1, 0x12) | (2, 0x28) => {
+            DataBlock::TiledTopology(parse_tiled_topology(payload, version)?.1)
+        }
+        (1, 0x03) => DataBlock::TypeITimings(
+            payload
+                .chunks_exact(DETAILED_TIMING_LENGTH)
+                .map(|timing| parse_detailed_timing(timing, 10).map(|(_, timing)| timing))
+                .collect::<Result<_, _>>()?,
+        ),
+        (_, 0x22) => DataBlock::TypeVIITimings(
+            payload
+                .chunks_exact(DETAILED_TIMING_LENGTH)
+                .map(|timing| parse_detailed_timing(timing, 1).map(|(_, timing)| timing))
+                .collect::<Result<_, _>>()?,
+        ),
+        (2, 0x2b) => {
+            // descriptors may be extended by the number of bytes given in the block revision
+            let descriptor_length =
+                ADAPTIVE_SYNC_DESCRIPTOR_LENGTH + ((revision >> 4) & 0x07) as usize;
+            DataBlock::AdaptiveSync(
+                payload
+                    .chunks_exact(descriptor_length)
+                    .map(|range| parse_adaptive_sync_range(range).map(|(_, range)| range))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+        _ => DataBlock::Unknown {
+            tag,
+            revision,
+            payload: payload.to_vec(),
+        },
+    };
+    Ok((i, block))
+}
+
+fn section_checksum_valid(section: &[u8]) -> bool {
+    section
+        .iter()
+        .fold(0u8, |sum, byte| sum.wrapping_add(*byte))
+        == 0
+}
+
+fn parse_section(i: &[u8]) -> Result<(&[u8], DisplayId), DisplayIdParseError> {
+    let full_input = i;
+    let (i, version) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?;
+    let (i, length) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?;
+    let (i, product_type) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?;
+    let (i, extension_count) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?;
+    let (i, mut payload) = take::<_, _, nom::error::Error<_>>(length as usize).parse(i)?;
+    let (i, _checksum) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?;
+    if !matches!(version >> 4, 1 | 2) {
+        return Err(DisplayIdParseError::UnsupportedVersion(version));
+    }
+    if !section_checksum_valid(&full_input[..SECTION_HEADER_LENGTH + length as usize + 1]) {
+        return Err(DisplayIdParseError::InvalidChecksum);
+    }
+
+    let mut data_blocks = Vec::new();
+    // the remaining payload is padded with zeros after the last data block
+    while payload.len() >= 3 && payload.iter().any(|byte| *byte != 0) {
+        let (rest, block) = parse_data_block(payload, version >> 4)?;
+        data_blocks.push(block);
+        payload = rest;
+    }
+    Ok((
+        i,
+        DisplayId {
+            version: version >> 4,
+            revision: version & 0x0f,
+            product_type,
+            extension_count,
+            data_blocks,
+        },
+    ))
+}
+
+/// parse a DisplayID structure including all of its extension sections
+pub fn parse_display_id(data: &[u8]) -> Result<DisplayId, DisplayIdParseError> {
+    let (mut i, mut display_id) = parse_section(data)?;
+    for _ in 0..display_id.extension_count {
+        let (rest, extension) = parse_section(i)?;
+        display_id.data_blocks.extend(extension.data_blocks);
+        i = rest;
+    }
+    Ok(display_id)
+}
+
+/// parse a DisplayID section embedded in an edid extension block
+pub fn parse_display_id_extension(block: &[u8]) -> Result<DisplayId, DisplayIdParseError> {
+    match block.split_first() {
+        Some((&DISPLAY_ID_EXTENSION_TAG, section)) => Ok(parse_section(section)?.1),
+        _ => Err(DisplayIdParseError::UnexpectedExtension),
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DisplayIdParseError {
+    #[error("Checksum is invalid, data corrupt!")]
+    InvalidChecksum,
+    #[error("Unsupported DisplayID version {0:#x}")]
+    UnsupportedVersion(u8),
+    #[error("Extension block does not contain DisplayID data")]
+    UnexpectedExtension,
+    #[error("Parsing data failed: {0}")]
+    NomParserError(String),
+}
+
+impl<T> From<nom::Err<T>> for DisplayIdParseError
+where
+    T: core::fmt::Debug,
+{
+    fn from(value: nom::Err<T>) -> Self {
+        DisplayIdParseError::NomParserError(format!("{value}"))
+    }
+}
+
+#[cfg(test)]
+pub(super) mod test {
+    use super::{parse_display_id, parse_display_id_extension, ManufacturerId};
+    use crate::ddc::edid::EdidBuilder;
+
+    /// standalone DisplayID 1.2 structure of the left tile of a 7680x4320 display
+    const DISPLAY_ID_V1: [u8; 70] = [
+        0x12, 0x41, 0x03, 0x00, 0x00, 0x00, 0x0e, 0x44, 0x45, 0x4c, 0x78, 0x56, 0x01, 0x00, 0x00,
+        0x00, 0x0a, 0x18, 0x02, 0x38, 0x4b, 0x03, 0x00, 0x14, 0x7f, 0x9c, 0x01, 0x80, 0xff, 0x0e,
+        0x9f, 0x00, 0x2f, 0x80, 0x1f, 0x00, 0xdf, 0x10, 0x4f, 0x00, 0x02, 0x00, 0x04, 0x00, 0x12,
+        0x00, 0x16, 0x80, 0x10, 0x00, 0x00, 0xff, 0x0e, 0xdf, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x44, 0x45, 0x4c, 0x78, 0x56, 0x01, 0x00, 0x00, 0x00, 0x88,
+    ];
+
+    pub(in crate::ddc) const DISPLAY_ID_EXTENSION: [u8; 128] = [
+        0x70, 0x20, 0x4c, 0x03, 0x00, 0x20, 0x00, 0x10, 0x00, 0x10, 0xfa, 0x34, 0x12, 0x01, 0x00,
+        0x00, 0x00, 0x10, 0x1c, 0x04, 0x54, 0x69, 0x6c, 0x65, 0x28, 0x00, 0x16, 0xc0, 0x10, 0x10,
+        0x00, 0xff, 0x09, 0x3f, 0x0b, 0x0a, 0x01, 0x02, 0x03, 0x04, 0x41, 0x50, 0x50, 0x34, 0x12,
+        0x01, 0x00, 0x00, 0x00, 0x22, 0x00, 0x14, 0x85, 0x53, 0x07, 0x80, 0xff, 0x09, 0x9f, 0x00,
+        0x2f, 0x80, 0x1f, 0x00, 0x3f, 0x0b, 0x3d, 0x00, 0x02, 0x00, 0x05, 0x00, 0x2b, 0x00, 0x06,
+        0x01, 0x00, 0x30, 0x8f, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x90,
+    ];
+
+    #[test]
+    fn parse_display_id_v2_extension() {
+        let display_id = parse_display_id_extension(&DISPLAY_ID_EXTENSION).unwrap();
+        assert_eq!(display_id.version, 2);
+        assert_eq!(display_id.data_blocks.len(), 4);
+
+        let product = display_id.product_identification().unwrap();
+        assert_eq!(product.manufacturer, ManufacturerId::Oui(0x0010fa));
+        assert_eq!(product.product, 0x1234);
+        assert_eq!(product.product_name, "Tile");
+
+        let tile = display_id.tiled_topology().unwrap();
+        assert!(tile.single_enclosure);
+        assert_eq!((tile.horizontal_tiles, tile.vertical_tiles), (2, 1));
+        assert_eq!((tile.horizontal_location, tile.vertical_location), (1, 0));
+        assert_eq!((tile.tile_width, tile.tile_height), (2560, 2880));
+        assert_eq!(tile.bezel, Some([1.0, 2.0, 3.0, 4.0]));
+        assert_eq!(tile.topology_vendor, ManufacturerId::Oui(0x415050));
+
+        let timings = display_id.type_vii_timings();
+        assert_eq!(timings.len(), 1);
+        assert!(timings[0].preferred);
+        assert_eq!(timings[0].horizontal_active_pixels, 2560);
+        assert_eq!(timings[0].vertical_active_lines, 2880);
+        assert_eq!(timings[0].refresh_rate().round(), 60.0);
+        assert!(timings[0].horizontal_sync_positive && !timings[0].vertical_sync_positive);
+
+        let ranges = display_id.adaptive_sync_ranges();
+        assert_eq!(ranges[0].min_refresh_rate, 48);
+        assert_eq!(ranges[0].max_refresh_rate, 144);
+    }
+
+    #[test]
+    fn parse_display_id_v1() {
+        let display_id = parse_display_id(&DISPLAY_ID_V1).unwrap();
+        assert_eq!((display_id.version, display_id.revision), (1, 2));
+        assert_eq!(display_id.data_blocks.len(), 3);
+
+        let product = display_id.product_identification().unwrap();
+        assert_eq!(product.manufacturer, ManufacturerId::Pnp(['D', 'E', 'L']));
+        assert_eq!(product.product, 0x5678);
+        assert_eq!(product.product_name, "8K");
+
+        let timings = display_id.type_i_timings();
+        assert_eq!(timings.len(), 1);
+        assert!(timings[0].preferred);
+        assert_eq!(timings[0].pixel_clock, 1_056_000);
+        assert_eq!(timings[0].horizontal_active_pixels, 3840);
+        assert_eq!(timings[0].vertical_active_lines, 4320);
+        assert_eq!(timings[0].refresh_rate().round(), 60.0);
+        assert!(timings[0].horizontal_sync_positive && !timings[0].vertical_sync_positive);
+
+        let tile = display_id.tiled_topology().unwrap();
+        assert!(tile.single_enclosure);
+        assert_eq!((tile.horizontal_tiles, tile.vertical_tiles), (2, 1));
+        assert_eq!((tile.horizontal_location, tile.vertical_location), (0, 0));
+        assert_eq!((tile.tile_width, tile.tile_height), (3840, 4320));
+        assert_eq!(tile.bezel, None);
+        assert_eq!(tile.topology_vendor, ManufacturerId::Pnp(['D', 'E', 'L']));
+    }
+
+    #[test]
+    fn embedded_display_id_sections() {
+        let edid = EdidBuilder::new()
+            .extension(DISPLAY_ID_EXTENSION)
+            .extension(DISPLAY_ID_EXTENSION)
+            .build()
+            .unwrap();
+        let display_id = edid.display_id().unwrap().unwrap();
+        assert_eq!(display_id.version, 2);
+        assert_eq!(display_id.data_blocks.len(), 8);
+
+        let edid = EdidBuilder::new().build().unwrap();
+        assert_eq!(edid.display_id().unwrap(), None);
+    }
+}
@@ -0,0 +1,209 @@
+//! Builder for synthetic edid data
+
+use thiserror::Error;
+
+use super::{
+    parse_edid, Chromaticity, CieXy, ColorEncoding, ColorSupport, Descriptor, DigitalInterface,
+    Display, Edid, EdidParseError, EstablishedTimings, FeatureSupport, Header, StandardTiming,
+    VideoInput, EDID_BLOCK_LENGTH,
+};
+
+const MAX_STANDARD_TIMINGS: usize = 8;
+const MAX_DESCRIPTORS: usize = 4;
+const MAX_EXTENSIONS: usize = u8::MAX as usize;
+
+#[derive(Error, Debug)]
+pub enum EdidBuildError {
+    #[error("At most {MAX_STANDARD_TIMINGS} standard timings fit into the base block")]
+    TooManyStandardTimings,
+    #[error("At most {MAX_DESCRIPTORS} descriptors fit into the base block")]
+    TooManyDescriptors,
+    #[error("At most {MAX_EXTENSIONS} extension blocks can be announced")]
+    TooManyExtensions,
+    #[error(transparent)]
+    Parse(#[from] EdidParseError),
+}
+
+/// Builder for edid data e.g. for virtual displays or tests
+///
+/// ```rust
+/// use libmonitor::ddc::edid::{Descriptor, EdidBuilder};
+///
+/// let edid = EdidBuilder::new()
+///     .vendor(['A', 'B', 'C'])
+///     .product(0x1234)
+///     .descriptor(Descriptor::ProductName("Virtual".to_string()))
+///     .build()
+///     .unwrap();
+/// let data = edid.to_bytes();
+/// assert_eq!(data.len(), 128);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EdidBuilder {
+    header: Header,
+    display: Display,
+    chromaticity: Chromaticity,
+    established_timings: EstablishedTimings,
+    standard_timings: Vec<StandardTiming>,
+    descriptors: Vec<Descriptor>,
+    extensions: Vec<[u8; EDID_BLOCK_LENGTH]>,
+}
+
+impl Default for EdidBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EdidBuilder {
+    /// create an edid 1.4 description of a digital display using sRGB primaries
+    pub fn new() -> Self {
+        Self {
+            header: Header {
+                vendor: ['A', 'A', 'A'],
+                product: 0,
+                serial: 0,
+                week: 0,
+                year: 0,
+                version: 1,
+                revision: 4,
+            },
+            display: Display {
+                video_input: VideoInput::Digital {
+                    bit_depth: None,
+                    interface: DigitalInterface::Undefined,
+                },
+                width: 0,
+                height: 0,
+                gamma: Some(2.2),
+                features: FeatureSupport {
+                    dpms_standby: false,
+                    dpms_suspend: false,
+                    dpms_active_off: false,
+                    color: ColorSupport::Encoding(ColorEncoding::Rgb444),
+                    srgb_default: true,
+                    preferred_timing_native: false,
+                    continuous_frequency: false,
+                },
+            },
+            chromaticity: Chromaticity {
+                red: CieXy { x: 0.64, y: 0.33 },
+                green: CieXy { x: 0.30, y: 0.60 },
+                blue: CieXy { x: 0.15, y: 0.06 },
+                white: CieXy {
+                    x: 0.3127,
+                    y: 0.3290,
+                },
+            },
+            established_timings: EstablishedTimings { bitmap: [0; 3] },
+            standard_timings: Vec::new(),
+            descriptors: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+
+    pub fn vendor(mut self, vendor: [char; 3]) -> Self {
+        self.header.vendor = vendor;
+        self
+    }
+
+    pub fn product(mut self, product: u16) -> Self {
+        self.header.product = product;
+        self
+    }
+
+    pub fn serial(mut self, serial: u32) -> Self {
+        self.header.serial = serial;
+        self
+    }
+
+    /// week and year of manufacture, the year has to be in the range of 1990 to 2245
+    pub fn manufacture_date(mut self, week: u8, year: u16) -> Self {
+        self.header.week = week;
+        self.header.year = year.saturating_sub(1990).min(u8::MAX as u16) as u8;
+        self
+    }
+
+    pub fn version(mut self, version: u8, revision: u8) -> Self {
+        self.header.version = version;
+        self.header.revision = revision;
+        self
+    }
+
+    pub fn video_input(mut self, video_input: VideoInput) -> Self {
+        self.display.video_input = video_input;
+        self
+    }
+
+    /// screen size in cm
+    pub fn screen_size(mut self, width: u8, height: u8) -> Self {
+        self.display.width = width;
+        self.display.height = height;
+        self
+    }
+
+    pub fn gamma(mut self, gamma: Option<f32>) -> Self {
+        self.display.gamma = gamma;
+        self
+    }
+
+    pub fn features(mut self, features: FeatureSupport) -> Self {
+        self.display.features = features;
+        self
+    }
+
+    pub fn chromaticity(mut self, chromaticity: Chromaticity) -> Self {
+        self.chromaticity = chromaticity;
+        self
+    }
+
+    pub fn established_timings(mut self, established_timings: EstablishedTimings) -> Self {
+        self.established_timings = established_timings;
+        self
+    }
+
+    /// add a standard timing, at most 8 standard timings are available, [`Self::build`] fails
+    /// if more are added
+    pub fn standard_timing(mut self, timing: StandardTiming) -> Self {
+        self.standard_timings.push(timing);
+        self
+    }
+
+    /// add a display descriptor, at most 4 descriptors are available, [`Self::build`] fails
+    /// if more are added
+    pub fn descriptor(mut self, descriptor: Descriptor) -> Self {
+        self.descriptors.push(descriptor);
+        self
+    }
+
+    /// add a raw extension block, the checksum is computed on serialization
+    pub fn extension(mut self, extension: [u8; EDID_BLOCK_LENGTH]) -> Self {
+        self.extensions.push(extension);
+        self
+    }
+
+    /// build the edid, the data is serialized and parsed again so the result matches the edid
+    /// that would be read from a display
+    pub fn build(self) -> Result<Edid, EdidBuildError> {
+        if self.standard_timings.len() > MAX_STANDARD_TIMINGS {
+            return Err(EdidBuildError::TooManyStandardTimings);
+        }
+        if self.descriptors.len() > MAX_DESCRIPTORS {
+            return Err(EdidBuildError::TooManyDescriptors);
+        }
+        if self.extensions.len() > MAX_EXTENSIONS {
+            return Err(EdidBuildError::TooManyExtensions);
+        }
+        let edid = Edid {
+            header: self.header,
+            display: self.display,
+            chromaticity: self.chromaticity,
+            established_timings: self.established_timings,
+            standard_timings: self.standard_timings,
+            descriptors: self.descriptors,
+            num_extr: self.extensions.len() as u8,
+            extensions: self.extensions,
+        };
+        Ok(parse_edid(&edid.to_bytes())?)
+    }
+}
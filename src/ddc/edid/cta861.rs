@@ -0,0 +1,651 @@
+//! CTA-861
+//!
+//! Parsing of the CTA-861 edid extension block used by most HDMI and DisplayPort displays
+
+use std::fmt::Display;
+
+use nom::bytes::complete::take;
+use nom::number::complete::le_u8;
+use nom::{IResult, Parser};
+
+use super::{parse_detailed_timing, DetailedTiming, EdidParseError, EDID_BLOCK_LENGTH};
+
+pub const CTA861_EXTENSION_TAG: u8 = 0x02;
+
+const DETAILED_TIMING_LENGTH: usize = 18;
+
+const HDMI_OUI: u32 = 0x000c03;
+const HDMI_FORUM_OUI: u32 = 0xc45dd8;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cta861Extension {
+    pub revision: u8,
+    /// display underscans IT video formats by default
+    pub underscan: bool,
+    pub basic_audio: bool,
+    pub ycbcr444: bool,
+    pub ycbcr422: bool,
+    /// number of native detailed timing descriptors
+    pub native_detailed_timings: u8,
+    pub data_blocks: Vec<DataBlock>,
+    pub detailed_timings: Vec<DetailedTiming>,
+}
+
+impl Cta861Extension {
+    /// short video descriptors of all video data blocks
+    pub fn video_descriptors(&self) -> Vec<ShortVideoDescriptor> {
+        self.data_blocks
+            .iter()
+            .filter_map(|block| match block {
+                DataBlock::Video(descriptors) => Some(descriptors.iter().copied()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// video identification codes marked as native by the display
+    pub fn native_vics(&self) -> Vec<u8> {
+        self.video_descriptors()
+            .iter()
+            .filter(|descriptor| descriptor.native)
+            .map(|descriptor| descriptor.vic)
+            .collect()
+    }
+
+    /// video identification codes in order of preference of the display
+    pub fn preferred_vics(&self) -> Vec<u8> {
+        self.data_blocks
+            .iter()
+            .filter_map(|block| match block {
+                DataBlock::VideoFormatPreference(vics) => Some(vics.iter().copied()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub fn audio_descriptors(&self) -> Vec<ShortAudioDescriptor> {
+        self.data_blocks
+            .iter()
+            .filter_map(|block| match block {
+                DataBlock::Audio(descriptors) => Some(descriptors.iter().copied()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub fn speaker_allocation(&self) -> Option<&SpeakerAllocation> {
+        self.data_blocks.iter().find_map(|block| match block {
+            DataBlock::SpeakerAllocation(speakers) => Some(speakers),
+            _ => None,
+        })
+    }
+
+    pub fn hdmi(&self) -> Option<&HdmiVendorDataBlock> {
+        self.data_blocks.iter().find_map(|block| match block {
+            DataBlock::Hdmi(hdmi) => Some(hdmi),
+            _ => None,
+        })
+    }
+
+    pub fn hdmi_forum(&self) -> Option<&HdmiForumVendorDataBlock> {
+        self.data_blocks.iter().find_map(|block| match block {
+            DataBlock::HdmiForum(hdmi_forum) => Some(hdmi_forum),
+            _ => None,
+        })
+    }
+
+    pub fn colorimetry(&self) -> Option<&Colorimetry> {
+        self.data_blocks.iter().find_map(|block| match block {
+            DataBlock::Colorimetry(colorimetry) => Some(colorimetry),
+            _ => None,
+        })
+    }
+
+    pub fn hdr_static_metadata(&self) -> Option<&HdrStaticMetadata> {
+        self.data_blocks.iter().find_map(|block| match block {
+            DataBlock::HdrStaticMetadata(metadata) => Some(metadata),
+            _ => None,
+        })
+    }
+
+    /// display supports HDR10 (SMPTE ST 2084 transfer function with static metadata type 1)
+    pub fn supports_hdr10(&self) -> bool {
+        self.hdr_static_metadata()
+            .is_some_and(|metadata| metadata.supports_hdr10())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DataBlock {
+    Audio(Vec<ShortAudioDescriptor>),
+    Video(Vec<ShortVideoDescriptor>),
+    SpeakerAllocation(SpeakerAllocation),
+    Hdmi(HdmiVendorDataBlock),
+    HdmiForum(HdmiForumVendorDataBlock),
+    VendorSpecific {
+        oui: u32,
+        payload: Vec<u8>,
+    },
+    Colorimetry(Colorimetry),
+    HdrStaticMetadata(HdrStaticMetadata),
+    /// video identification codes in order of preference
+    VideoFormatPreference(Vec<u8>),
+    Extended {
+        tag: u8,
+        payload: Vec<u8>,
+    },
+    Unknown {
+        tag: u8,
+        payload: Vec<u8>,
+    },
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ShortVideoDescriptor {
+    /// video identification code
+    pub vic: u8,
+    pub native: bool,
+}
+
+impl From<u8> for ShortVideoDescriptor {
+    fn from(value: u8) -> Self {
+        // codes 129 to 192 mark native formats for the vics 1 to 64
+        match value {
+            129..=192 => Self {
+                vic: value & 0x7f,
+                native: true,
+            },
+            _ => Self {
+                vic: value,
+                native: false,
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum AudioFormat {
+    Lpcm,
+    Ac3,
+    Mpeg1,
+    Mp3,
+    Mpeg2,
+    AacLc,
+    Dts,
+    Atrac,
+    OneBitAudio,
+    EnhancedAc3,
+    DtsHd,
+    Mat,
+    Dst,
+    WmaPro,
+    Extended(u8),
+    Reserved(u8),
+}
+
+impl From<u8> for AudioFormat {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::Lpcm,
+            0x02 => Self::Ac3,
+            0x03 => Self::Mpeg1,
+            0x04 => Self::Mp3,
+            0x05 => Self::Mpeg2,
+            0x06 => Self::AacLc,
+            0x07 => Self::Dts,
+            0x08 => Self::Atrac,
+            0x09 => Self::OneBitAudio,
+            0x0a => Self::EnhancedAc3,
+            0x0b => Self::DtsHd,
+            0x0c => Self::Mat,
+            0x0d => Self::Dst,
+            0x0e => Self::WmaPro,
+            0x0f => Self::Extended(value),
+            _ => Self::Reserved(value),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ShortAudioDescriptor {
+    pub format: AudioFormat,
+    pub max_channels: u8,
+    sample_rates: u8,
+    /// bit depths for LPCM, maximum bit rate for compressed formats or format specific data
+    format_data: u8,
+}
+
+impl ShortAudioDescriptor {
+    /// supported sample rates in Hz
+    pub fn sample_rates(&self) -> Vec<u32> {
+        [32000, 44100, 48000, 88200, 96000, 176400, 192000]
+            .into_iter()
+            .enumerate()
+            .filter(|(bit, _)| self.sample_rates & (1 << bit) != 0)
+            .map(|(_, rate)| rate)
+            .collect()
+    }
+
+    /// supported bit depths of LPCM audio
+    pub fn bit_depths(&self) -> Vec<u8> {
+        if self.format != AudioFormat::Lpcm {
+            return Vec::new();
+        }
+        [16, 20, 24]
+            .into_iter()
+            .enumerate()
+            .filter(|(bit, _)| self.format_data & (1 << bit) != 0)
+            .map(|(_, depth)| depth)
+            .collect()
+    }
+
+    /// maximum bit rate in kHz of compressed audio formats
+    pub fn max_bit_rate(&self) -> Option<u32> {
+        match self.format {
+            AudioFormat::Ac3
+            | AudioFormat::Mpeg1
+            | AudioFormat::Mp3
+            | AudioFormat::Mpeg2
+            | AudioFormat::AacLc
+            | AudioFormat::Dts
+            | AudioFormat::Atrac => Some(self.format_data as u32 * 8),
+            _ => None,
+        }
+    }
+}
+
+fn parse_short_audio_descriptor(i: &[u8]) -> IResult<&[u8], ShortAudioDescriptor> {
+    let (i, format) = le_u8.parse(i)?;
+    let (i, sample_rates) = le_u8.parse(i)?;
+    let (i, format_data) = le_u8.parse(i)?;
+    Ok((
+        i,
+        ShortAudioDescriptor {
+            format: ((format >> 3) & 0x0f).into(),
+            max_channels: (format & 0x07) + 1,
+            sample_rates: sample_rates & 0x7f,
+            format_data,
+        },
+    ))
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Speaker {
+    FrontLeftRight,
+    LowFrequencyEffects,
+    FrontCenter,
+    BackLeftRight,
+    BackCenter,
+    FrontLeftRightCenter,
+    RearLeftRightCenter,
+    FrontLeftRightWide,
+    TopFrontLeftRight,
+    TopCenter,
+    TopFrontCenter,
+    LeftRightSurround,
+    LowFrequencyEffects2,
+    TopBackCenter,
+    SideLeftRight,
+    TopSideLeftRight,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SpeakerAllocation {
+    allocation: u16,
+}
+
+impl SpeakerAllocation {
+    pub fn speakers(&self) -> Vec<Speaker> {
+        use Speaker::*;
+        [
+            FrontLeftRight,
+            LowFrequencyEffects,
+            FrontCenter,
+            BackLeftRight,
+            BackCenter,
+            FrontLeftRightCenter,
+            RearLeftRightCenter,
+            FrontLeftRightWide,
+            TopFrontLeftRight,
+            TopCenter,
+            TopFrontCenter,
+            LeftRightSurround,
+            LowFrequencyEffects2,
+            TopBackCenter,
+            SideLeftRight,
+            TopSideLeftRight,
+        ]
+        .into_iter()
+        .enumerate()
+        .filter(|(bit, _)| self.allocation & (1 << bit) != 0)
+        .map(|(_, speaker)| speaker)
+        .collect()
+    }
+}
+
+/// source physical address of the display in the HDMI CEC topology
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct PhysicalAddress(pub [u8; 4]);
+
+impl Display for PhysicalAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [a, b, c, d] = self.0;
+        write!(f, "{a}.{b}.{c}.{d}")
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct HdmiVendorDataBlock {
+    pub physical_address: PhysicalAddress,
+    pub supports_ai: bool,
+    pub deep_color_30bit: bool,
+    pub deep_color_36bit: bool,
+    pub deep_color_48bit: bool,
+    pub deep_color_ycbcr444: bool,
+    pub dvi_dual_link: bool,
+    /// maximum TMDS clock in MHz
+    pub max_tmds_clock: Option<u32>,
+}
+
+impl From<&[u8]> for HdmiVendorDataBlock {
+    fn from(payload: &[u8]) -> Self {
+        let byte = |n: usize| payload.get(n).copied().unwrap_or_default();
+        let flags = byte(2);
+        Self {
+            physical_address: PhysicalAddress([
+                byte(0) >> 4,
+                byte(0) & 0x0f,
+                byte(1) >> 4,
+                byte(1) & 0x0f,
+            ]),
+            supports_ai: flags & 0x80 != 0,
+            deep_color_48bit: flags & 0x40 != 0,
+            deep_color_36bit: flags & 0x20 != 0,
+            deep_color_30bit: flags & 0x10 != 0,
+            deep_color_ycbcr444: flags & 0x08 != 0,
+            dvi_dual_link: flags & 0x01 != 0,
+            max_tmds_clock: Some(byte(3) as u32 * 5).filter(|clock| *clock != 0),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct HdmiForumVendorDataBlock {
+    pub version: u8,
+    /// maximum TMDS character rate in MHz
+    pub max_tmds_character_rate: Option<u32>,
+    pub scdc_present: bool,
+    pub scdc_read_request: bool,
+    pub scrambling_below_340mcsc: bool,
+    pub deep_color_420_30bit: bool,
+    pub deep_color_420_36bit: bool,
+    pub deep_color_420_48bit: bool,
+    /// maximum fixed rate link rate index, 0 if FRL is not supported
+    pub max_frl_rate: u8,
+}
+
+impl From<&[u8]> for HdmiForumVendorDataBlock {
+    fn from(payload: &[u8]) -> Self {
+        let byte = |n: usize| payload.get(n).copied().unwrap_or_default();
+        let flags = byte(2);
+        let deep_color = byte(3);
+        Self {
+            version: byte(0),
+            max_tmds_character_rate: Some(byte(1) as u32 * 5).filter(|rate| *rate != 0),
+            scdc_present: flags & 0x80 != 0,
+            scdc_read_request: flags & 0x40 != 0,
+            scrambling_below_340mcsc: flags & 0x08 != 0,
+            deep_color_420_48bit: deep_color & 0x04 != 0,
+            deep_color_420_36bit: deep_color & 0x02 != 0,
+            deep_color_420_30bit: deep_color & 0x01 != 0,
+            max_frl_rate: deep_color >> 4,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Colorimetry {
+    pub xv_ycc_601: bool,
+    pub xv_ycc_709: bool,
+    pub s_ycc_601: bool,
+    pub op_ycc_601: bool,
+    pub op_rgb: bool,
+    pub bt2020_c_ycc: bool,
+    pub bt2020_ycc: bool,
+    pub bt2020_rgb: bool,
+    pub ictcp: bool,
+    pub dci_p3: bool,
+}
+
+impl From<&[u8]> for Colorimetry {
+    fn from(payload: &[u8]) -> Self {
+        let byte = |n: usize| payload.get(n).copied().unwrap_or_default();
+        Self {
+            xv_ycc_601: byte(0) & 0x01 != 0,
+            xv_ycc_709: byte(0) & 0x02 != 0,
+            s_ycc_601: byte(0) & 0x04 != 0,
+            op_ycc_601: byte(0) & 0x08 != 0,
+            op_rgb: byte(0) & 0x10 != 0,
+            bt2020_c_ycc: byte(0) & 0x20 != 0,
+            bt2020_ycc: byte(0) & 0x40 != 0,
+            bt2020_rgb: byte(0) & 0x80 != 0,
+            ictcp: byte(1) & 0x40 != 0,
+            dci_p3: byte(1) & 0x80 != 0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct HdrStaticMetadata {
+    pub eotf_traditional_sdr: bool,
+    pub eotf_traditional_hdr: bool,
+    /// SMPTE ST 2084 (PQ) transfer function
+    pub eotf_smpte_st2084: bool,
+    /// hybrid log gamma transfer function
+    pub eotf_hlg: bool,
+    pub static_metadata_type1: bool,
+    /// desired content max luminance in cd/m²
+    pub max_luminance: Option<f32>,
+    /// desired content max frame-average luminance in cd/m²
+    pub max_frame_average_luminance: Option<f32>,
+    /// desired content min luminance in cd/m²
+    pub min_luminance: Option<f32>,
+}
+
+impl HdrStaticMetadata {
+    pub fn supports_hdr10(&self) -> bool {
+        self.eotf_smpte_st2084 && self.static_metadata_type1
+    }
+}
+
+fn decode_luminance(code: u8) -> f32 {
+    50.0 * 2.0f32.powf(code as f32 / 32.0)
+}
+
+impl From<&[u8]> for HdrStaticMetadata {
+    fn from(payload: &[u8]) -> Self {
+        let byte = |n: usize| payload.get(n).copied().unwrap_or_default();
+        let max_luminance = payload.get(2).map(|code| decode_luminance(*code));
+        Self {
+            eotf_traditional_sdr: byte(0) & 0x01 != 0,
+            eotf_traditional_hdr: byte(0) & 0x02 != 0,
+            eotf_smpte_st2084: byte(0) & 0x04 != 0,
+            eotf_hlg: byte(0) & 0x08 != 0,
+            static_metadata_type1: byte(1) & 0x01 != 0,
+            max_luminance,
+            max_frame_average_luminance: payload.get(3).map(|code| decode_luminance(*code)),
+            // min luminance is encoded relative to the max luminance
+            min_luminance: payload.get(4).and_then(|code| {
+                max_luminance.map(|max| max * (*code as f32 / 255.0).powi(2) / 100.0)
+            }),
+        }
+    }
+}
+
+fn parse_vendor_specific_block(payload: &[u8]) -> DataBlock {
+    if payload.len() < 3 {
+        return DataBlock::Unknown {
+            tag: 0x03,
+            payload: payload.to_vec(),
+        };
+    }
+    let oui = u32::from_le_bytes([payload[0], payload[1], payload[2], 0]);
+    match oui {
+        HDMI_OUI => DataBlock::Hdmi(payload[3..].into()),
+        HDMI_FORUM_OUI => DataBlock::HdmiForum(payload[3..].into()),
+        _ => DataBlock::VendorSpecific {
+            oui,
+            payload: payload[3..].to_vec(),
+        },
+    }
+}
+
+fn parse_extended_block(payload: &[u8]) -> DataBlock {
+    match payload.split_first() {
+        Some((0x05, data)) => DataBlock::Colorimetry(data.into()),
+        Some((0x06, data)) => DataBlock::HdrStaticMetadata(data.into()),
+        Some((0x0d, data)) => DataBlock::VideoFormatPreference(data.to_vec()),
+        Some((tag, data)) => DataBlock::Extended {
+            tag: *tag,
+            payload: data.to_vec(),
+        },
+        None => DataBlock::Unknown {
+            tag: 0x07,
+            payload: Vec::new(),
+        },
+    }
+}
+
+fn parse_data_block(i: &[u8]) -> IResult<&[u8], DataBlock> {
+    let (i, header) = le_u8.parse(i)?;
+    let (i, payload) = take((header & 0x1f) as usize).parse(i)?;
+    let block = match header >> 5 {
+        0x01 => DataBlock::Audio(
+            payload
+                .chunks_exact(3)
+                .filter_map(|sad| parse_short_audio_descriptor(sad).ok())
+                .map(|(_, sad)| sad)
+                .collect(),
+        ),
+        0x02 => DataBlock::Video(payload.iter().map(|svd| (*svd).into()).collect()),
+        0x03 => parse_vendor_specific_block(payload),
+        0x04 => DataBlock::SpeakerAllocation(SpeakerAllocation {
+            allocation: u16::from_le_bytes([
+                payload.first().copied().unwrap_or_default(),
+                payload.get(1).copied().unwrap_or_default(),
+            ]),
+        }),
+        0x07 => parse_extended_block(payload),
+        tag => DataBlock::Unknown {
+            tag,
+            payload: payload.to_vec(),
+        },
+    };
+    Ok((i, block))
+}
+
+pub fn parse_cta861_extension(block: &[u8]) -> Result<Cta861Extension, EdidParseError> {
+    if block.len() < EDID_BLOCK_LENGTH || block[0] != CTA861_EXTENSION_TAG {
+        return Err(EdidParseError::UnexpectedExtension);
+    }
+    let (_, revision) = le_u8::<&[u8], nom::error::Error<_>>.parse(&block[1..])?;
+    let detailed_timing_offset = block[2] as usize;
+    let flags = block[3];
+
+    // an offset of 0 indicates that neither data blocks nor detailed timings are present
+    let mut data_blocks = Vec::new();
+    let mut detailed_timings = Vec::new();
+    if detailed_timing_offset >= 4 {
+        let checksum_offset = EDID_BLOCK_LENGTH - 1;
+        let mut i = &block[4..detailed_timing_offset.min(checksum_offset)];
+        while !i.is_empty() {
+            let (rest, data_block) = parse_data_block(i)?;
+            data_blocks.push(data_block);
+            i = rest;
+        }
+        for descriptor in block[detailed_timing_offset.min(checksum_offset)..checksum_offset]
+            .chunks_exact(DETAILED_TIMING_LENGTH)
+            // padding after the last descriptor starts with a zero pixel clock
+            .take_while(|descriptor| descriptor[0] != 0 || descriptor[1] != 0)
+        {
+            let (_, timing) = parse_detailed_timing(descriptor)?;
+            detailed_timings.push(timing);
+        }
+    }
+
+    Ok(Cta861Extension {
+        revision,
+        underscan: flags & 0x80 != 0,
+        basic_audio: flags & 0x40 != 0,
+        ycbcr444: flags & 0x20 != 0,
+        ycbcr422: flags & 0x10 != 0,
+        native_detailed_timings: flags & 0x0f,
+        data_blocks,
+        detailed_timings,
+    })
+}
+
+#[cfg(test)]
+pub(super) mod test {
+    use super::{parse_cta861_extension, AudioFormat, PhysicalAddress, Speaker};
+
+    pub(in crate::ddc) const CTA861_BLOCK: [u8; 128] = [
+        0x02, 0x03, 0x2f, 0xf1, 0x44, 0x90, 0x04, 0x1f, 0x61, 0x23, 0x09, 0x07, 0x07, 0x83, 0x01,
+        0x00, 0x00, 0x67, 0x03, 0x0c, 0x00, 0x10, 0x00, 0xb8, 0x3c, 0x67, 0xd8, 0x5d, 0xc4, 0x01,
+        0x78, 0x80, 0x07, 0xe3, 0x05, 0xc0, 0x00, 0xe6, 0x06, 0x05, 0x01, 0x78, 0x5c, 0x00, 0xe2,
+        0x0d, 0x10, 0x02, 0x3a, 0x80, 0x18, 0x71, 0x38, 0x2d, 0x40, 0x58, 0x2c, 0x45, 0x00, 0xc4,
+        0x8e, 0x21, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3a,
+    ];
+
+    #[test]
+    fn parse_cta861_block() {
+        let cta = parse_cta861_extension(&CTA861_BLOCK).unwrap();
+        assert_eq!(cta.revision, 3);
+        assert!(cta.underscan && cta.basic_audio && cta.ycbcr444 && cta.ycbcr422);
+        assert_eq!(cta.native_detailed_timings, 1);
+
+        assert_eq!(cta.native_vics(), vec![16]);
+        assert_eq!(cta.preferred_vics(), vec![16]);
+        assert_eq!(cta.video_descriptors().len(), 4);
+
+        let audio = cta.audio_descriptors();
+        assert_eq!(audio[0].format, AudioFormat::Lpcm);
+        assert_eq!(audio[0].max_channels, 2);
+        assert_eq!(audio[0].sample_rates(), vec![32000, 44100, 48000]);
+        assert_eq!(audio[0].bit_depths(), vec![16, 20, 24]);
+        assert_eq!(
+            cta.speaker_allocation().unwrap().speakers(),
+            vec![Speaker::FrontLeftRight]
+        );
+
+        let hdmi = cta.hdmi().unwrap();
+        assert_eq!(hdmi.physical_address, PhysicalAddress([1, 0, 0, 0]));
+        assert_eq!(hdmi.physical_address.to_string(), "1.0.0.0");
+        assert!(hdmi.deep_color_30bit && hdmi.deep_color_36bit && !hdmi.deep_color_48bit);
+        assert_eq!(hdmi.max_tmds_clock, Some(300));
+
+        let hdmi_forum = cta.hdmi_forum().unwrap();
+        assert_eq!(hdmi_forum.max_tmds_character_rate, Some(600));
+        assert!(hdmi_forum.scdc_present);
+
+        let colorimetry = cta.colorimetry().unwrap();
+        assert!(colorimetry.bt2020_rgb && colorimetry.bt2020_ycc && !colorimetry.dci_p3);
+
+        assert!(cta.supports_hdr10());
+        let hdr = cta.hdr_static_metadata().unwrap();
+        assert!(!hdr.eotf_hlg);
+        assert_eq!(hdr.max_luminance.unwrap().round(), 673.0);
+        assert_eq!(hdr.min_luminance, Some(0.0));
+
+        assert_eq!(cta.detailed_timings.len(), 1);
+        assert_eq!(cta.detailed_timings[0].horizontal_active_pixels, 1920);
+        assert_eq!(cta.detailed_timings[0].vertical_active_lines, 1080);
+    }
+}
@@ -0,0 +1,106 @@
+//! partial list of PNP ids of display, panel and graphics vendors, not the full UEFI PNP registry
+
+/// common display vendor PNP ids, sorted by id
+static DISPLAY_VENDOR_IDS: &[(&str, &str)] = &[
+    ("AAC", "AcerView"),
+    ("ACI", "Ancor Communications Inc"),
+    ("ACR", "Acer Technologies"),
+    ("AOC", "AOC"),
+    ("APP", "Apple Computer Inc"),
+    ("AUO", "AU Optronics"),
+    ("AUS", "ASUSTek COMPUTER INC"),
+    ("BNQ", "BenQ Corporation"),
+    ("BOE", "BOE"),
+    ("CMN", "Chimei Innolux Corporation"),
+    ("CMO", "Chi Mei Optoelectronics corp."),
+    ("CPQ", "Compaq Computer Company"),
+    ("CTX", "Chuntex Electronic Co., Ltd."),
+    ("DEL", "Dell Inc."),
+    ("DON", "DENON, Ltd."),
+    ("DWE", "Daewoo Electronics Company Ltd"),
+    ("ECS", "Elitegroup Computer Systems Company Ltd"),
+    ("EIZ", "Eizo Nanao Corporation"),
+    ("ENC", "Eizo Nanao Corporation"),
+    ("EPI", "Envision Peripherals, Inc"),
+    ("FNI", "Funai Electric Co., Ltd."),
+    ("FUS", "Fujitsu Siemens Computers GmbH"),
+    ("GBT", "GIGA-BYTE TECHNOLOGY CO., LTD."),
+    ("GGL", "Google Inc."),
+    ("GSM", "LG Electronics"),
+    ("GWY", "Gateway 2000"),
+    ("HEC", "Hisense Electric Co., Ltd."),
+    ("HEI", "Hyundai Electronics Industries Co., Ltd."),
+    ("HIQ", "Kaohsiung Opto Electronics Americas, Inc."),
+    ("HPN", "HP Inc."),
+    ("HRE", "Qingdao Haier Electronics Co., Ltd."),
+    ("HSD", "HannStar Display Corp"),
+    ("HTC", "Hitachi Ltd"),
+    ("HVR", "HTC Corporation"),
+    ("HWP", "Hewlett Packard"),
+    ("HWV", "Huawei Technologies Co., Inc."),
+    ("IVM", "Iiyama North America"),
+    ("IVO", "InfoVision Optoelectronics"),
+    ("LEN", "Lenovo Group Limited"),
+    ("LGD", "LG Display"),
+    ("LPL", "LG Philips"),
+    ("MAG", "MAG InnoVision"),
+    ("MAX", "Maxdata Computer GmbH"),
+    ("MEI", "Panasonic Industry Company"),
+    ("MEL", "Mitsubishi Electric Corporation"),
+    ("MSH", "Microsoft"),
+    ("MSI", "Microstep"),
+    ("NEC", "NEC Corporation"),
+    ("NOK", "Nokia Display Products"),
+    ("NVD", "Nvidia"),
+    ("ONK", "ONKYO Corporation"),
+    ("OVR", "Oculus VR, Inc."),
+    ("PHL", "Philips Consumer Electronics Company"),
+    ("PIO", "Pioneer Electronic Corporation"),
+    ("PKB", "Packard Bell Electronics"),
+    ("PNR", "Planar Systems, Inc."),
+    ("QDS", "Quanta Display Inc."),
+    ("RHT", "Red Hat, Inc."),
+    ("SAM", "Samsung Electric Company"),
+    ("SDC", "Samsung Display Corp"),
+    ("SEC", "Seiko Epson Corporation"),
+    ("SEM", "Samsung Electronics Company Ltd"),
+    ("SHP", "Sharp Corporation"),
+    ("SNY", "Sony"),
+    ("SPT", "Sceptre Tech Inc"),
+    ("STN", "Samsung Electronics America"),
+    ("SYN", "Synaptics Inc"),
+    ("TAT", "Tatung Co. of America Inc"),
+    ("TOS", "Toshiba Corporation"),
+    ("TPV", "Top Victory Electronics ( Fujian ) Company Ltd"),
+    ("TSB", "Toshiba America Info Systems Inc"),
+    ("VIZ", "VIZIO, Inc"),
+    ("VLV", "Valve Corporation"),
+    ("VSC", "ViewSonic Corporation"),
+    ("WAC", "Wacom Tech"),
+    ("XMI", "Xiaomi"),
+    ("YMH", "Yamaha Corporation"),
+];
+
+/// look up the registered company name of a PNP id, `None` for ids missing from the list
+pub fn manufacturer_name(id: &[char; 3]) -> Option<&'static str> {
+    let id = id.iter().collect::<String>();
+    DISPLAY_VENDOR_IDS
+        .binary_search_by(|(entry, _)| (*entry).cmp(id.as_str()))
+        .ok()
+        .map(|index| DISPLAY_VENDOR_IDS[index].1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{manufacturer_name, DISPLAY_VENDOR_IDS};
+
+    #[test]
+    fn lookup_manufacturer_name() {
+        assert!(DISPLAY_VENDOR_IDS
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(manufacturer_name(&['D', 'E', 'L']), Some("Dell Inc."));
+        assert_eq!(manufacturer_name(&['G', 'S', 'M']), Some("LG Electronics"));
+        assert_eq!(manufacturer_name(&['Z', 'Z', 'Z']), None);
+    }
+}
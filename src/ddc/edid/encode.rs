@@ -0,0 +1,448 @@
+//! Serialization of parsed edid data back into its binary representation
+
+use super::{
+    AnalogSignalLevel, AspectRatio, CieXy, ColorEncoding, ColorManagement, ColorSupport,
+    CvtSupport, CvtTimingCode, Descriptor, DetailedTiming, DigitalInterface, Display,
+    DisplayColorType, Edid, FeatureSupport, Header, RangeLimits, StandardTiming, TimingFormula,
+    VideoInput, WhitePoint, CP437_FORWARD_TABLE, EDID_BLOCK_LENGTH, EDID_HEADER,
+};
+
+const DESCRIPTOR_DATA_LENGTH: usize = 13;
+const UNUSED_STANDARD_TIMING: [u8; 2] = [0x01, 0x01];
+
+impl Edid {
+    /// serialize the edid base block followed by all extension blocks, checksums are computed
+    /// for every block
+    ///
+    /// standard timings exceeding the eight and descriptors exceeding the four available slots
+    /// are dropped, missing descriptors are filled with dummy descriptors. The announced number
+    /// of extension blocks is taken from the extensions that are serialized, at most 255
+    /// extensions are written. Use [`super::EdidBuilder`] to reject data that does not fit.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let extensions = &self.extensions[..self.extensions.len().min(u8::MAX as usize)];
+        let version = (self.header.version, self.header.revision);
+        let mut data = Vec::with_capacity(EDID_BLOCK_LENGTH * (1 + extensions.len()));
+        encode_header(&mut data, &self.header);
+        encode_display(&mut data, &self.display);
+        encode_chromaticity(&mut data, &self.chromaticity);
+        data.extend_from_slice(&self.established_timings.bitmap);
+        encode_standard_timings(&mut data, &self.standard_timings, 8, version);
+        for n in 0..4 {
+            encode_descriptor(
+                &mut data,
+                self.descriptors.get(n).unwrap_or(&Descriptor::Dummy),
+                version,
+            );
+        }
+        data.push(extensions.len() as u8);
+        push_checksum(&mut data);
+
+        for extension in extensions {
+            data.extend_from_slice(&extension[..EDID_BLOCK_LENGTH - 1]);
+            push_checksum(&mut data);
+        }
+        data
+    }
+}
+
+/// append the checksum of the last incomplete block
+fn push_checksum(data: &mut Vec<u8>) {
+    let block = &data[data.len() / EDID_BLOCK_LENGTH * EDID_BLOCK_LENGTH..];
+    let sum = block.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    data.push(0u8.wrapping_sub(sum));
+}
+
+fn encode_vendor(vendor: &[char; 3]) -> u16 {
+    // Each letter is 5 bits, 0x01 = A
+    vendor.iter().fold(0, |v, c| {
+        v << 5 | ((*c as u8).wrapping_sub(b'A' - 1) & 0x1F) as u16
+    })
+}
+
+fn encode_header(data: &mut Vec<u8>, header: &Header) {
+    data.extend_from_slice(&EDID_HEADER);
+    data.extend_from_slice(&encode_vendor(&header.vendor).to_be_bytes());
+    data.extend_from_slice(&header.product.to_le_bytes());
+    data.extend_from_slice(&header.serial.to_le_bytes());
+    data.extend_from_slice(&[header.week, header.year, header.version, header.revision]);
+}
+
+fn encode_video_input(video_input: &VideoInput) -> u8 {
+    match video_input {
+        VideoInput::Digital {
+            bit_depth,
+            interface,
+        } => {
+            let depth = bit_depth.map(|depth| (depth.saturating_sub(4) / 2) & 0x7);
+            let interface = match interface {
+                DigitalInterface::Undefined => 0x0,
+                DigitalInterface::Dvi => 0x1,
+                DigitalInterface::HdmiA => 0x2,
+                DigitalInterface::HdmiB => 0x3,
+                DigitalInterface::Mddi => 0x4,
+                DigitalInterface::DisplayPort => 0x5,
+                DigitalInterface::Reserved(value) => value & 0xf,
+            };
+            0x80 | depth.unwrap_or_default() << 4 | interface
+        }
+        VideoInput::Analog {
+            signal_level,
+            blank_to_black_setup,
+            separate_sync,
+            composite_sync,
+            sync_on_green,
+            serrated_vsync,
+        } => {
+            let level = match signal_level {
+                AnalogSignalLevel::Level0700_0300 => 0b00,
+                AnalogSignalLevel::Level0714_0286 => 0b01,
+                AnalogSignalLevel::Level1000_0400 => 0b10,
+                AnalogSignalLevel::Level0700_0000 => 0b11,
+            };
+            level << 5
+                | (*blank_to_black_setup as u8) << 4
+                | (*separate_sync as u8) << 3
+                | (*composite_sync as u8) << 2
+                | (*sync_on_green as u8) << 1
+                | *serrated_vsync as u8
+        }
+    }
+}
+
+fn encode_feature_support(features: &FeatureSupport) -> u8 {
+    let color = match features.color {
+        ColorSupport::Encoding(ColorEncoding::Rgb444)
+        | ColorSupport::DisplayType(DisplayColorType::Monochrome) => 0b00,
+        ColorSupport::Encoding(ColorEncoding::Rgb444YCrCb444)
+        | ColorSupport::DisplayType(DisplayColorType::Rgb) => 0b01,
+        ColorSupport::Encoding(ColorEncoding::Rgb444YCrCb422)
+        | ColorSupport::DisplayType(DisplayColorType::NonRgb) => 0b10,
+        ColorSupport::Encoding(ColorEncoding::Rgb444YCrCb444YCrCb422)
+        | ColorSupport::DisplayType(DisplayColorType::Undefined) => 0b11,
+    };
+    (features.dpms_standby as u8) << 7
+        | (features.dpms_suspend as u8) << 6
+        | (features.dpms_active_off as u8) << 5
+        | color << 3
+        | (features.srgb_default as u8) << 2
+        | (features.preferred_timing_native as u8) << 1
+        | features.continuous_frequency as u8
+}
+
+fn encode_gamma(gamma: Option<f32>) -> u8 {
+    // datavalue = (gamma*100)-100, 0xFF indicates gamma is defined in an extension
+    gamma.map_or(0xFF, |gamma| {
+        (gamma * 100.0 - 100.0).round().clamp(0.0, 254.0) as u8
+    })
+}
+
+fn encode_display(data: &mut Vec<u8>, display: &Display) {
+    data.extend_from_slice(&[
+        encode_video_input(&display.video_input),
+        display.width,
+        display.height,
+        encode_gamma(display.gamma),
+        encode_feature_support(&display.features),
+    ]);
+}
+
+/// 10 bit binary fraction of a color coordinate
+fn encode_coordinate(value: f32) -> u16 {
+    (value * 1024.0).round().clamp(0.0, 1023.0) as u16
+}
+
+/// high bytes of x and y and the two low bits of each packed into a nibble
+fn encode_cie_xy(xy: &CieXy) -> (u8, u8, u8) {
+    let x = encode_coordinate(xy.x);
+    let y = encode_coordinate(xy.y);
+    (
+        (x >> 2) as u8,
+        (y >> 2) as u8,
+        ((x & 0x3) << 2 | (y & 0x3)) as u8,
+    )
+}
+
+fn encode_chromaticity(data: &mut Vec<u8>, chromaticity: &super::Chromaticity) {
+    let (red_x, red_y, red_lo) = encode_cie_xy(&chromaticity.red);
+    let (green_x, green_y, green_lo) = encode_cie_xy(&chromaticity.green);
+    let (blue_x, blue_y, blue_lo) = encode_cie_xy(&chromaticity.blue);
+    let (white_x, white_y, white_lo) = encode_cie_xy(&chromaticity.white);
+    data.extend_from_slice(&[
+        red_lo << 4 | green_lo,
+        blue_lo << 4 | white_lo,
+        red_x,
+        red_y,
+        green_x,
+        green_y,
+        blue_x,
+        blue_y,
+        white_x,
+        white_y,
+    ]);
+}
+
+fn encode_standard_timing(timing: &StandardTiming, version: (u8, u8)) -> [u8; 2] {
+    let aspect = match timing.aspect_ratio {
+        AspectRatio::Ratio16x10 if version >= (1, 3) => 0b00,
+        AspectRatio::Ratio1x1 if version < (1, 3) => 0b00,
+        AspectRatio::Ratio4x3 => 0b01,
+        AspectRatio::Ratio5x4 => 0b10,
+        AspectRatio::Ratio16x9 => 0b11,
+        // ratios that can not be represented in this edid version
+        _ => return UNUSED_STANDARD_TIMING,
+    };
+    [
+        (timing.horizontal_active_pixels / 8).saturating_sub(31) as u8,
+        aspect << 6 | (timing.refresh_rate.saturating_sub(60) & 0x3f),
+    ]
+}
+
+fn encode_standard_timings(
+    data: &mut Vec<u8>,
+    timings: &[StandardTiming],
+    slots: usize,
+    version: (u8, u8),
+) {
+    for n in 0..slots {
+        data.extend_from_slice(&timings.get(n).map_or(UNUSED_STANDARD_TIMING, |timing| {
+            encode_standard_timing(timing, version)
+        }));
+    }
+}
+
+fn cp437_reverse(c: char) -> u8 {
+    CP437_FORWARD_TABLE
+        .iter()
+        .position(|code| *code as u32 == c as u32)
+        .map_or(b'?', |position| position as u8)
+}
+
+/// text is terminated by a line feed and padded with spaces
+fn encode_descriptor_text(text: &str) -> Vec<u8> {
+    let mut data: Vec<u8> = text
+        .chars()
+        .map(cp437_reverse)
+        .take(DESCRIPTOR_DATA_LENGTH)
+        .collect();
+    if data.len() < DESCRIPTOR_DATA_LENGTH {
+        data.push(0x0A);
+    }
+    data.resize(DESCRIPTOR_DATA_LENGTH, 0x20);
+    data
+}
+
+fn encode_detailed_timing(data: &mut Vec<u8>, timing: &DetailedTiming) {
+    let hi = |value: u16| ((value >> 8) & 0xf) as u8;
+    let lo = |value: u16| (value & 0xff) as u8;
+    data.extend_from_slice(&((timing.pixel_clock / 10) as u16).to_le_bytes());
+    data.extend_from_slice(&[
+        lo(timing.horizontal_active_pixels),
+        lo(timing.horizontal_blanking_pixels),
+        hi(timing.horizontal_active_pixels) << 4 | hi(timing.horizontal_blanking_pixels),
+        lo(timing.vertical_active_lines),
+        lo(timing.vertical_blanking_lines),
+        hi(timing.vertical_active_lines) << 4 | hi(timing.vertical_blanking_lines),
+        lo(timing.horizontal_front_porch),
+        lo(timing.horizontal_sync_width),
+        (timing.vertical_front_porch as u8 & 0xf) << 4 | (timing.vertical_sync_width as u8 & 0xf),
+        (hi(timing.horizontal_front_porch) & 0x3) << 6
+            | (hi(timing.horizontal_sync_width) & 0x3) << 4
+            | ((timing.vertical_front_porch >> 4) as u8 & 0x3) << 2
+            | ((timing.vertical_sync_width >> 4) as u8 & 0x3),
+        lo(timing.horizontal_size),
+        lo(timing.vertical_size),
+        hi(timing.horizontal_size) << 4 | hi(timing.vertical_size),
+        timing.horizontal_border_pixels,
+        timing.vertical_border_pixels,
+        timing.features,
+    ]);
+}
+
+fn encode_cvt_aspect_ratio(aspect_ratio: &AspectRatio) -> Option<u8> {
+    match aspect_ratio {
+        AspectRatio::Ratio4x3 => Some(0b000),
+        AspectRatio::Ratio16x9 => Some(0b001),
+        AspectRatio::Ratio16x10 => Some(0b010),
+        AspectRatio::Ratio5x4 => Some(0b011),
+        AspectRatio::Ratio15x9 => Some(0b100),
+        AspectRatio::Ratio1x1 => None,
+    }
+}
+
+fn encode_cvt_support(cvt: &CvtSupport) -> [u8; 7] {
+    let max_active_pixels = cvt.max_active_pixels.map_or(0, |pixels| pixels / 8);
+    [
+        cvt.version << 4 | (cvt.revision & 0xf),
+        ((cvt.pixel_clock_reduction / 0.25).round() as u8) << 2
+            | (max_active_pixels >> 8) as u8 & 0x3,
+        max_active_pixels as u8,
+        cvt.aspect_ratios
+            .iter()
+            .filter_map(encode_cvt_aspect_ratio)
+            .fold(0, |bitmap, bit| bitmap | 0x80 >> bit),
+        cvt.preferred_aspect_ratio
+            .as_ref()
+            .and_then(encode_cvt_aspect_ratio)
+            .unwrap_or(0b111)
+            << 5
+            | (cvt.reduced_blanking as u8) << 4
+            | (cvt.standard_blanking as u8) << 3,
+        cvt.scaling << 4,
+        cvt.preferred_refresh_rate,
+    ]
+}
+
+fn encode_range_limits(limits: &RangeLimits) -> (u8, Vec<u8>) {
+    // rates exceeding 255 are encoded using an offset of 255
+    let vertical_offset = if limits.min_vertical_rate > 255 {
+        0b11
+    } else if limits.max_vertical_rate > 255 {
+        0b10
+    } else {
+        0b00
+    };
+    let horizontal_offset = if limits.min_horizontal_rate > 255 {
+        0b11
+    } else if limits.max_horizontal_rate > 255 {
+        0b10
+    } else {
+        0b00
+    };
+    let rate = |value: u16, offset: bool| {
+        if offset {
+            value.saturating_sub(255) as u8
+        } else {
+            value as u8
+        }
+    };
+    let mut data = vec![
+        rate(limits.min_vertical_rate, vertical_offset == 0b11),
+        rate(limits.max_vertical_rate, vertical_offset != 0b00),
+        rate(limits.min_horizontal_rate, horizontal_offset == 0b11),
+        rate(limits.max_horizontal_rate, horizontal_offset != 0b00),
+        (limits.max_pixel_clock.unwrap_or_default() / 10) as u8,
+    ];
+    match &limits.timing_formula {
+        TimingFormula::DefaultGtf => data.push(0x00),
+        TimingFormula::RangeLimitsOnly => data.push(0x01),
+        TimingFormula::SecondaryGtf(gtf) => {
+            data.extend_from_slice(&[0x02, 0x00, (gtf.start_frequency / 2) as u8]);
+            data.push((gtf.c * 2.0).round() as u8);
+            data.extend_from_slice(&gtf.m.to_le_bytes());
+            data.extend_from_slice(&[gtf.k, (gtf.j * 2.0).round() as u8]);
+        }
+        TimingFormula::Cvt(cvt) => {
+            data.push(0x04);
+            data.extend_from_slice(&encode_cvt_support(cvt));
+        }
+        TimingFormula::Reserved(value) => data.push(*value),
+    }
+    if data.len() < DESCRIPTOR_DATA_LENGTH {
+        data.push(0x0A);
+    }
+    data.resize(DESCRIPTOR_DATA_LENGTH, 0x20);
+    (horizontal_offset << 2 | vertical_offset, data)
+}
+
+fn encode_white_points(white_points: &[WhitePoint]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(DESCRIPTOR_DATA_LENGTH);
+    for n in 0..2 {
+        match white_points.get(n) {
+            Some(white_point) => {
+                let (x, y, lo) = encode_cie_xy(&white_point.white);
+                data.extend_from_slice(&[
+                    white_point.index,
+                    lo,
+                    x,
+                    y,
+                    encode_gamma(white_point.gamma),
+                ]);
+            }
+            None => data.extend_from_slice(&[0x00; 5]),
+        }
+    }
+    data.extend_from_slice(&[0x0A, 0x20, 0x20]);
+    data
+}
+
+fn encode_color_management(color_management: &ColorManagement) -> Vec<u8> {
+    let mut data = vec![color_management.version];
+    for curve in [
+        &color_management.red,
+        &color_management.green,
+        &color_management.blue,
+    ] {
+        data.extend_from_slice(&((curve.a3 * 100.0).round() as u16).to_le_bytes());
+        data.extend_from_slice(&((curve.a2 * 100.0).round() as u16).to_le_bytes());
+    }
+    data
+}
+
+fn encode_cvt_timing_code(code: &CvtTimingCode) -> [u8; 3] {
+    let lines = (code.vertical_active_lines / 2).saturating_sub(1);
+    let aspect = match code.aspect_ratio {
+        AspectRatio::Ratio16x9 => 0b01,
+        AspectRatio::Ratio16x10 => 0b10,
+        AspectRatio::Ratio15x9 => 0b11,
+        _ => 0b00,
+    };
+    let rates = [50, 60, 75, 85];
+    let preferred = rates
+        .iter()
+        .position(|rate| *rate == code.preferred_refresh_rate)
+        .unwrap_or_default() as u8;
+    let supported = rates
+        .iter()
+        .enumerate()
+        .filter(|(_, rate)| code.refresh_rates.contains(rate))
+        .fold(0, |bitmap, (bit, _)| bitmap | 0x10 >> bit);
+    [
+        lines as u8,
+        ((lines >> 8) as u8 & 0xf) << 4 | aspect << 2,
+        preferred << 5 | supported | code.reduced_blanking as u8,
+    ]
+}
+
+fn encode_descriptor(data: &mut Vec<u8>, descriptor: &Descriptor, version: (u8, u8)) {
+    let (tag, flags, payload) = match descriptor {
+        Descriptor::DetailedTiming(timing) => return encode_detailed_timing(data, timing),
+        Descriptor::SerialNumber(text) => (0xFF, 0x00, encode_descriptor_text(text)),
+        Descriptor::UnspecifiedText(text) => (0xFE, 0x00, encode_descriptor_text(text)),
+        Descriptor::RangeLimits(limits) => {
+            let (offsets, payload) = encode_range_limits(limits);
+            (0xFD, offsets, payload)
+        }
+        Descriptor::ProductName(text) => (0xFC, 0x00, encode_descriptor_text(text)),
+        Descriptor::WhitePoint(white_points) => (0xFB, 0x00, encode_white_points(white_points)),
+        Descriptor::StandardTiming(timings) => {
+            let mut payload = Vec::with_capacity(DESCRIPTOR_DATA_LENGTH);
+            encode_standard_timings(&mut payload, timings, 6, version);
+            payload.push(0x0A);
+            (0xFA, 0x00, payload)
+        }
+        Descriptor::ColorManagement(color_management) => {
+            (0xF9, 0x00, encode_color_management(color_management))
+        }
+        Descriptor::TimingCodes(codes) => {
+            let mut payload = vec![0x01];
+            for n in 0..4 {
+                payload.extend_from_slice(&codes.get(n).map_or([0; 3], encode_cvt_timing_code));
+            }
+            (0xF8, 0x00, payload)
+        }
+        Descriptor::EstablishedTimings(timings) => {
+            let mut payload = vec![0x0A];
+            payload.extend_from_slice(&timings.bitmap);
+            payload.resize(DESCRIPTOR_DATA_LENGTH, 0x00);
+            (0xF7, 0x00, payload)
+        }
+        Descriptor::Dummy => (0x10, 0x00, vec![0x00; DESCRIPTOR_DATA_LENGTH]),
+        Descriptor::Unknown {
+            tag,
+            reserved,
+            data,
+        } => (*tag, *reserved, data.to_vec()),
+    };
+    data.extend_from_slice(&[0x00, 0x00, 0x00, tag, flags]);
+    data.extend_from_slice(&payload);
+}
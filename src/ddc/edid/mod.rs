@@ -0,0 +1,1454 @@
+use nom::bytes::complete::{tag, take};
+use nom::combinator::peek;
+use nom::multi::{count, fill};
+use nom::number::complete::{be_u16, le_u16, le_u32, le_u8};
+use nom::{IResult, Parser};
+use thiserror::Error;
+
+use super::display_id::{
+    parse_display_id_extension, DisplayId, DisplayIdParseError, DISPLAY_ID_EXTENSION_TAG,
+};
+
+pub mod cta861;
+
+pub mod display_vendors;
+
+pub mod report;
+
+mod builder;
+mod encode;
+
+pub use builder::{EdidBuildError, EdidBuilder};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Header {
+    pub vendor: [char; 3],
+    pub product: u16,
+    pub serial: u32,
+    pub week: u8,
+    pub year: u8, // Starting at year 1990
+    pub version: u8,
+    pub revision: u8,
+}
+
+/// fixed header pattern at the start of the base block
+const EDID_HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+
+impl Header {
+    /// registered company name of the vendor PNP id, e.g. "Dell Inc." for "DEL", only common
+    /// display vendors are known
+    pub fn manufacturer_name(&self) -> Option<&'static str> {
+        display_vendors::manufacturer_name(&self.vendor)
+    }
+}
+
+fn parse_vendor(v: u16) -> [char; 3] {
+    let mask: u8 = 0x1F; // Each letter is 5 bits
+    let i0 = b'A' - 1; // 0x01 = A
+    [
+        (((v >> 10) as u8 & mask) + i0) as char,
+        (((v >> 5) as u8 & mask) + i0) as char,
+        ((v as u8 & mask) + i0) as char,
+    ]
+}
+
+fn parse_header(i: &[u8]) -> IResult<&[u8], Header> {
+    let (i, _) = tag(&EDID_HEADER[..]).parse(i)?;
+    let (i, vendor) = be_u16.parse(i)?;
+    let (i, product) = le_u16.parse(i)?;
+    let (i, serial) = le_u32.parse(i)?;
+    let (i, week) = le_u8.parse(i)?;
+    let (i, year) = le_u8.parse(i)?;
+    let (i, version) = le_u8.parse(i)?;
+    let (i, revision) = le_u8.parse(i)?;
+    Ok((
+        i,
+        Header {
+            vendor: parse_vendor(vendor),
+            product,
+            serial,
+            week,
+            year,
+            version,
+            revision,
+        },
+    ))
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DigitalInterface {
+    Undefined,
+    Dvi,
+    HdmiA,
+    HdmiB,
+    Mddi,
+    DisplayPort,
+    Reserved(u8),
+}
+
+impl From<u8> for DigitalInterface {
+    fn from(value: u8) -> Self {
+        match value {
+            0x0 => Self::Undefined,
+            0x1 => Self::Dvi,
+            0x2 => Self::HdmiA,
+            0x3 => Self::HdmiB,
+            0x4 => Self::Mddi,
+            0x5 => Self::DisplayPort,
+            _ => Self::Reserved(value),
+        }
+    }
+}
+
+/// video white and sync levels relative to blank
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AnalogSignalLevel {
+    /// +0.7/−0.3 V
+    Level0700_0300,
+    /// +0.714/−0.286 V
+    Level0714_0286,
+    /// +1.0/−0.4 V
+    Level1000_0400,
+    /// +0.7/0 V
+    Level0700_0000,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum VideoInput {
+    Digital {
+        /// bits per color channel, `None` if undefined
+        bit_depth: Option<u8>,
+        interface: DigitalInterface,
+    },
+    Analog {
+        signal_level: AnalogSignalLevel,
+        blank_to_black_setup: bool,
+        separate_sync: bool,
+        composite_sync: bool,
+        sync_on_green: bool,
+        serrated_vsync: bool,
+    },
+}
+
+impl VideoInput {
+    pub fn is_digital(&self) -> bool {
+        matches!(self, VideoInput::Digital { .. })
+    }
+}
+
+fn parse_video_input(value: u8, version: (u8, u8)) -> VideoInput {
+    if value & 0x80 != 0 {
+        // bit depth and interface are only defined starting with edid 1.4
+        if version < (1, 4) {
+            return VideoInput::Digital {
+                bit_depth: None,
+                interface: DigitalInterface::Undefined,
+            };
+        }
+        VideoInput::Digital {
+            bit_depth: match value >> 4 & 0x7 {
+                depth @ 0b001..=0b110 => Some(depth * 2 + 4),
+                _ => None,
+            },
+            interface: (value & 0xf).into(),
+        }
+    } else {
+        VideoInput::Analog {
+            signal_level: match value >> 5 & 0x3 {
+                0b00 => AnalogSignalLevel::Level0700_0300,
+                0b01 => AnalogSignalLevel::Level0714_0286,
+                0b10 => AnalogSignalLevel::Level1000_0400,
+                _ => AnalogSignalLevel::Level0700_0000,
+            },
+            blank_to_black_setup: value & 0x10 != 0,
+            separate_sync: value & 0x08 != 0,
+            composite_sync: value & 0x04 != 0,
+            sync_on_green: value & 0x02 != 0,
+            serrated_vsync: value & 0x01 != 0,
+        }
+    }
+}
+
+/// Color encodings supported by digital displays
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ColorEncoding {
+    Rgb444,
+    Rgb444YCrCb444,
+    Rgb444YCrCb422,
+    Rgb444YCrCb444YCrCb422,
+}
+
+/// Color type of analog displays
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DisplayColorType {
+    Monochrome,
+    Rgb,
+    NonRgb,
+    Undefined,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ColorSupport {
+    Encoding(ColorEncoding),
+    DisplayType(DisplayColorType),
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct FeatureSupport {
+    pub dpms_standby: bool,
+    pub dpms_suspend: bool,
+    pub dpms_active_off: bool,
+    pub color: ColorSupport,
+    /// sRGB is the default color space
+    pub srgb_default: bool,
+    /// the preferred timing mode includes the native pixel format and refresh rate, before edid
+    /// 1.4 the first detailed timing is the preferred timing
+    pub preferred_timing_native: bool,
+    /// the display is continuous frequency, before edid 1.4 GTF timings are supported
+    pub continuous_frequency: bool,
+}
+
+fn parse_feature_support(value: u8, video_input: &VideoInput, version: (u8, u8)) -> FeatureSupport {
+    let color_bits = value >> 3 & 0x3;
+    // digital displays report color encodings starting with edid 1.4
+    let color = if video_input.is_digital() && version >= (1, 4) {
+        ColorSupport::Encoding(match color_bits {
+            0b00 => ColorEncoding::Rgb444,
+            0b01 => ColorEncoding::Rgb444YCrCb444,
+            0b10 => ColorEncoding::Rgb444YCrCb422,
+            _ => ColorEncoding::Rgb444YCrCb444YCrCb422,
+        })
+    } else {
+        ColorSupport::DisplayType(match color_bits {
+            0b00 => DisplayColorType::Monochrome,
+            0b01 => DisplayColorType::Rgb,
+            0b10 => DisplayColorType::NonRgb,
+            _ => DisplayColorType::Undefined,
+        })
+    };
+    FeatureSupport {
+        dpms_standby: value & 0x80 != 0,
+        dpms_suspend: value & 0x40 != 0,
+        dpms_active_off: value & 0x20 != 0,
+        color,
+        srgb_default: value & 0x04 != 0,
+        preferred_timing_native: value & 0x02 != 0,
+        continuous_frequency: value & 0x01 != 0,
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Display {
+    pub video_input: VideoInput,
+    pub width: u8,  // cm
+    pub height: u8, // cm
+    /// display gamma in the range of 1.00–3.54, `None` if it is defined in an extension block
+    pub gamma: Option<f32>,
+    pub features: FeatureSupport,
+}
+
+fn parse_display(i: &[u8], version: (u8, u8)) -> IResult<&[u8], Display> {
+    let (i, video_input) = le_u8.parse(i)?;
+    let (i, width) = le_u8.parse(i)?;
+    let (i, height) = le_u8.parse(i)?;
+    let (i, gamma) = le_u8.parse(i)?;
+    let (i, features) = le_u8.parse(i)?;
+    let video_input = parse_video_input(video_input, version);
+    Ok((
+        i,
+        Display {
+            video_input,
+            width,
+            height,
+            // datavalue = (gamma*100)-100
+            gamma: (gamma != 0xff).then_some((gamma as f32 + 100.0) / 100.0),
+            features: parse_feature_support(features, &video_input, version),
+        },
+    ))
+}
+
+/// color coordinates in the CIE 1931 xy color space
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct CieXy {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl CieXy {
+    fn from_bits(x_hi: u8, y_hi: u8, lo: u8) -> Self {
+        // coordinates are 10 bit binary fractions
+        let decode = |hi: u8, lo: u8| ((hi as u16) << 2 | lo as u16) as f32 / 1024.0;
+        Self {
+            x: decode(x_hi, lo >> 2 & 0x3),
+            y: decode(y_hi, lo & 0x3),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Chromaticity {
+    pub red: CieXy,
+    pub green: CieXy,
+    pub blue: CieXy,
+    pub white: CieXy,
+}
+
+fn parse_chromaticity(i: &[u8]) -> IResult<&[u8], Chromaticity> {
+    let (i, red_green_lo) = le_u8.parse(i)?;
+    let (i, blue_white_lo) = le_u8.parse(i)?;
+    let mut hi = [0; 8];
+    let (i, _) = fill(le_u8, &mut hi).parse(i)?;
+    Ok((
+        i,
+        Chromaticity {
+            red: CieXy::from_bits(hi[0], hi[1], red_green_lo >> 4),
+            green: CieXy::from_bits(hi[2], hi[3], red_green_lo & 0xf),
+            blue: CieXy::from_bits(hi[4], hi[5], blue_white_lo >> 4),
+            white: CieXy::from_bits(hi[6], hi[7], blue_white_lo & 0xf),
+        },
+    ))
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct VideoMode {
+    pub width: u16,
+    pub height: u16,
+    /// refresh rate in Hz
+    pub refresh_rate: u16,
+    pub interlaced: bool,
+    pub reduced_blanking: bool,
+}
+
+impl VideoMode {
+    const fn new(width: u16, height: u16, refresh_rate: u16) -> Self {
+        Self {
+            width,
+            height,
+            refresh_rate,
+            interlaced: false,
+            reduced_blanking: false,
+        }
+    }
+
+    const fn reduced_blanking(width: u16, height: u16, refresh_rate: u16) -> Self {
+        Self {
+            width,
+            height,
+            refresh_rate,
+            interlaced: false,
+            reduced_blanking: true,
+        }
+    }
+}
+
+/// video modes of the established timing bitmap starting with the most significant bit
+const ESTABLISHED_TIMING_MODES: [VideoMode; 17] = [
+    VideoMode::new(720, 400, 70),
+    VideoMode::new(720, 400, 88),
+    VideoMode::new(640, 480, 60),
+    VideoMode::new(640, 480, 67),
+    VideoMode::new(640, 480, 72),
+    VideoMode::new(640, 480, 75),
+    VideoMode::new(800, 600, 56),
+    VideoMode::new(800, 600, 60),
+    VideoMode::new(800, 600, 72),
+    VideoMode::new(800, 600, 75),
+    VideoMode::new(832, 624, 75),
+    VideoMode {
+        width: 1024,
+        height: 768,
+        refresh_rate: 87,
+        interlaced: true,
+        reduced_blanking: false,
+    },
+    VideoMode::new(1024, 768, 60),
+    VideoMode::new(1024, 768, 70),
+    VideoMode::new(1024, 768, 75),
+    VideoMode::new(1280, 1024, 75),
+    VideoMode::new(1152, 870, 75),
+];
+
+/// Bitmap of the supported established timings
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct EstablishedTimings {
+    pub bitmap: [u8; 3],
+}
+
+impl EstablishedTimings {
+    pub fn modes(&self) -> Vec<VideoMode> {
+        ESTABLISHED_TIMING_MODES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.bitmap[bit / 8] & (0x80 >> (bit % 8)) != 0)
+            .map(|(_, mode)| *mode)
+            .collect()
+    }
+
+    /// manufacturer specific timings in the lower bits of the last byte
+    pub fn manufacturer_timings(&self) -> u8 {
+        self.bitmap[2] & 0x7f
+    }
+}
+
+fn parse_established_timings(i: &[u8]) -> IResult<&[u8], EstablishedTimings> {
+    let mut bitmap = [0; 3];
+    let (i, _) = fill(le_u8, &mut bitmap).parse(i)?;
+    Ok((i, EstablishedTimings { bitmap }))
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AspectRatio {
+    Ratio1x1,
+    Ratio16x10,
+    Ratio4x3,
+    Ratio5x4,
+    Ratio16x9,
+    Ratio15x9,
+}
+
+impl AspectRatio {
+    /// vertical lines for the given horizontal pixels
+    pub fn vertical_lines(&self, horizontal_pixels: u16) -> u16 {
+        let (width, height) = match self {
+            AspectRatio::Ratio1x1 => (1, 1),
+            AspectRatio::Ratio16x10 => (16, 10),
+            AspectRatio::Ratio4x3 => (4, 3),
+            AspectRatio::Ratio5x4 => (5, 4),
+            AspectRatio::Ratio16x9 => (16, 9),
+            AspectRatio::Ratio15x9 => (15, 9),
+        };
+        (horizontal_pixels as u32 * height / width) as u16
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct StandardTiming {
+    pub horizontal_active_pixels: u16,
+    pub aspect_ratio: AspectRatio,
+    /// refresh rate in Hz
+    pub refresh_rate: u8,
+}
+
+impl StandardTiming {
+    pub fn vertical_active_lines(&self) -> u16 {
+        self.aspect_ratio
+            .vertical_lines(self.horizontal_active_pixels)
+    }
+}
+
+/// parse a two byte standard timing, unused entries are marked as 0x01 0x01
+fn parse_standard_timing(i: &[u8], version: (u8, u8)) -> IResult<&[u8], Option<StandardTiming>> {
+    let (i, horizontal) = le_u8.parse(i)?;
+    let (i, aspect_refresh) = le_u8.parse(i)?;
+    if horizontal == 0x00 || (horizontal == 0x01 && aspect_refresh == 0x01) {
+        return Ok((i, None));
+    }
+    let aspect_ratio = match aspect_refresh >> 6 {
+        // edid versions prior to 1.3 defined 1:1 instead of 16:10
+        0b00 if version < (1, 3) => AspectRatio::Ratio1x1,
+        0b00 => AspectRatio::Ratio16x10,
+        0b01 => AspectRatio::Ratio4x3,
+        0b10 => AspectRatio::Ratio5x4,
+        _ => AspectRatio::Ratio16x9,
+    };
+    Ok((
+        i,
+        Some(StandardTiming {
+            horizontal_active_pixels: (horizontal as u16 + 31) * 8,
+            aspect_ratio,
+            refresh_rate: (aspect_refresh & 0x3f) + 60,
+        }),
+    ))
+}
+
+fn parse_standard_timings(i: &[u8], version: (u8, u8)) -> IResult<&[u8], Vec<StandardTiming>> {
+    let (i, timings) = count(|i| parse_standard_timing(i, version), 8)(i)?;
+    Ok((i, timings.into_iter().flatten().collect()))
+}
+
+fn parse_descriptor_text(i: &[u8]) -> IResult<&[u8], String> {
+    let (i, encoded) = take(13_usize).parse(i)?;
+    let decoded = encoded
+        .iter()
+        .filter(|b| **b != 0x0A)
+        .map(|b| cp437_forward(*b))
+        .collect::<String>();
+    Ok((i, decoded.trim().to_string()))
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct DetailedTiming {
+    /// Pixel clock in kHz.
+    pub pixel_clock: u32,
+    pub horizontal_active_pixels: u16,
+    pub horizontal_blanking_pixels: u16,
+    pub vertical_active_lines: u16,
+    pub vertical_blanking_lines: u16,
+    pub horizontal_front_porch: u16,
+    pub horizontal_sync_width: u16,
+    pub vertical_front_porch: u16,
+    pub vertical_sync_width: u16,
+    /// Horizontal size in millimeters
+    pub horizontal_size: u16,
+    /// Vertical size in millimeters
+    pub vertical_size: u16,
+    /// Border pixels on one side of screen (i.e. total number is twice this)
+    pub horizontal_border_pixels: u8,
+    /// Border pixels on one side of screen (i.e. total number is twice this)
+    pub vertical_border_pixels: u8,
+    pub features: u8, /* TODO add enums etc. */
+}
+
+fn parse_detailed_timing(i: &[u8]) -> IResult<&[u8], DetailedTiming> {
+    let (i, pixel_clock_10khz) = le_u16.parse(i)?;
+    let (i, horizontal_active_lo) = le_u8.parse(i)?;
+    let (i, horizontal_blanking_lo) = le_u8.parse(i)?;
+    let (i, horizontal_px_hi) = le_u8.parse(i)?;
+    let (i, vertical_active_lo) = le_u8.parse(i)?;
+    let (i, vertical_blanking_lo) = le_u8.parse(i)?;
+    let (i, vertical_px_hi) = le_u8.parse(i)?;
+    let (i, horizontal_front_porch_lo) = le_u8.parse(i)?;
+    let (i, horizontal_sync_width_lo) = le_u8.parse(i)?;
+    let (i, vertical_lo) = le_u8.parse(i)?;
+    let (i, porch_sync_hi) = le_u8.parse(i)?;
+    let (i, horizontal_size_lo) = le_u8.parse(i)?;
+    let (i, vertical_size_lo) = le_u8.parse(i)?;
+    let (i, size_hi) = le_u8.parse(i)?;
+    let (i, horizontal_border) = le_u8.parse(i)?;
+    let (i, vertical_border) = le_u8.parse(i)?;
+    let (i, features) = le_u8.parse(i)?;
+    Ok((
+        i,
+        DetailedTiming {
+            pixel_clock: pixel_clock_10khz as u32 * 10,
+            horizontal_active_pixels: (horizontal_active_lo as u16)
+                | (((horizontal_px_hi >> 4) as u16) << 8),
+            horizontal_blanking_pixels: (horizontal_blanking_lo as u16)
+                | (((horizontal_px_hi & 0xf) as u16) << 8),
+            vertical_active_lines: (vertical_active_lo as u16)
+                | (((vertical_px_hi >> 4) as u16) << 8),
+            vertical_blanking_lines: (vertical_blanking_lo as u16)
+                | (((vertical_px_hi & 0xf) as u16) << 8),
+            horizontal_front_porch: (horizontal_front_porch_lo as u16)
+                | (((porch_sync_hi >> 6) as u16) << 8),
+            horizontal_sync_width: (horizontal_sync_width_lo as u16)
+                | ((((porch_sync_hi >> 4) & 0x3) as u16) << 8),
+            vertical_front_porch: ((vertical_lo >> 4) as u16)
+                | ((((porch_sync_hi >> 2) & 0x3) as u16) << 4),
+            vertical_sync_width: ((vertical_lo & 0xf) as u16)
+                | (((porch_sync_hi & 0x3) as u16) << 4),
+            horizontal_size: (horizontal_size_lo as u16) | (((size_hi >> 4) as u16) << 8),
+            vertical_size: (vertical_size_lo as u16) | (((size_hi & 0xf) as u16) << 8),
+            horizontal_border_pixels: horizontal_border,
+            vertical_border_pixels: vertical_border,
+            features,
+        },
+    ))
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SecondaryGtf {
+    /// start break frequency in kHz
+    pub start_frequency: u16,
+    pub c: f32,
+    pub m: u16,
+    pub k: u8,
+    pub j: f32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CvtSupport {
+    pub version: u8,
+    pub revision: u8,
+    /// additional precision of the maximum pixel clock in MHz, subtracted from the range limit
+    pub pixel_clock_reduction: f32,
+    /// maximum active pixels per line, `None` if there is no limit
+    pub max_active_pixels: Option<u16>,
+    pub aspect_ratios: Vec<AspectRatio>,
+    pub preferred_aspect_ratio: Option<AspectRatio>,
+    pub reduced_blanking: bool,
+    pub standard_blanking: bool,
+    /// horizontal shrink, horizontal stretch, vertical shrink and vertical stretch support
+    pub scaling: u8,
+    /// preferred vertical refresh rate in Hz
+    pub preferred_refresh_rate: u8,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TimingFormula {
+    DefaultGtf,
+    RangeLimitsOnly,
+    SecondaryGtf(SecondaryGtf),
+    Cvt(CvtSupport),
+    Reserved(u8),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RangeLimits {
+    /// vertical rates in Hz
+    pub min_vertical_rate: u16,
+    pub max_vertical_rate: u16,
+    /// horizontal rates in kHz
+    pub min_horizontal_rate: u16,
+    pub max_horizontal_rate: u16,
+    /// maximum pixel clock in MHz
+    pub max_pixel_clock: Option<u32>,
+    pub timing_formula: TimingFormula,
+}
+
+fn parse_cvt_aspect_ratio(bits: u8) -> Option<AspectRatio> {
+    match bits {
+        0b000 => Some(AspectRatio::Ratio4x3),
+        0b001 => Some(AspectRatio::Ratio16x9),
+        0b010 => Some(AspectRatio::Ratio16x10),
+        0b011 => Some(AspectRatio::Ratio5x4),
+        0b100 => Some(AspectRatio::Ratio15x9),
+        _ => None,
+    }
+}
+
+fn parse_range_limits(i: &[u8], offsets: u8) -> IResult<&[u8], RangeLimits> {
+    let (i, min_vertical_rate) = le_u8.parse(i)?;
+    let (i, max_vertical_rate) = le_u8.parse(i)?;
+    let (i, min_horizontal_rate) = le_u8.parse(i)?;
+    let (i, max_horizontal_rate) = le_u8.parse(i)?;
+    let (i, max_pixel_clock) = le_u8.parse(i)?;
+    let (i, timing_support) = le_u8.parse(i)?;
+    let (i, data) = take(7_usize).parse(i)?;
+    let timing_formula = match timing_support {
+        0x00 => TimingFormula::DefaultGtf,
+        0x01 => TimingFormula::RangeLimitsOnly,
+        0x02 => TimingFormula::SecondaryGtf(SecondaryGtf {
+            start_frequency: data[1] as u16 * 2,
+            c: data[2] as f32 / 2.0,
+            m: u16::from_le_bytes([data[3], data[4]]),
+            k: data[5],
+            j: data[6] as f32 / 2.0,
+        }),
+        0x04 => TimingFormula::Cvt(CvtSupport {
+            version: data[0] >> 4,
+            revision: data[0] & 0xf,
+            pixel_clock_reduction: (data[1] >> 2) as f32 * 0.25,
+            max_active_pixels: Some(((data[1] & 0x3) as u16) << 8 | data[2] as u16)
+                .filter(|pixels| *pixels != 0)
+                .map(|pixels| pixels * 8),
+            aspect_ratios: (0..5)
+                .filter(|bit| data[3] & (0x80 >> bit) != 0)
+                .filter_map(parse_cvt_aspect_ratio)
+                .collect(),
+            preferred_aspect_ratio: parse_cvt_aspect_ratio(data[4] >> 5),
+            reduced_blanking: data[4] & 0x10 != 0,
+            standard_blanking: data[4] & 0x08 != 0,
+            scaling: data[5] >> 4,
+            preferred_refresh_rate: data[6],
+        }),
+        _ => TimingFormula::Reserved(timing_support),
+    };
+    // rate offsets are only available starting with edid 1.4
+    let vertical_offset = |bits: u8| if offsets & 0x3 >= bits { 255 } else { 0 };
+    let horizontal_offset = |bits: u8| if offsets >> 2 & 0x3 >= bits { 255 } else { 0 };
+    Ok((
+        i,
+        RangeLimits {
+            min_vertical_rate: min_vertical_rate as u16 + vertical_offset(0b11),
+            max_vertical_rate: max_vertical_rate as u16 + vertical_offset(0b10),
+            min_horizontal_rate: min_horizontal_rate as u16 + horizontal_offset(0b11),
+            max_horizontal_rate: max_horizontal_rate as u16 + horizontal_offset(0b10),
+            max_pixel_clock: Some(max_pixel_clock as u32 * 10).filter(|clock| *clock != 0),
+            timing_formula,
+        },
+    ))
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct WhitePoint {
+    pub index: u8,
+    pub white: CieXy,
+    pub gamma: Option<f32>,
+}
+
+fn parse_white_points(i: &[u8]) -> IResult<&[u8], Vec<WhitePoint>> {
+    let mut white_points = Vec::new();
+    let mut i = i;
+    for _ in 0..2 {
+        let (rest, index) = le_u8.parse(i)?;
+        let (rest, lo) = le_u8.parse(rest)?;
+        let (rest, x_hi) = le_u8.parse(rest)?;
+        let (rest, y_hi) = le_u8.parse(rest)?;
+        let (rest, gamma) = le_u8.parse(rest)?;
+        // an index of 0 marks an unused entry
+        if index != 0 {
+            white_points.push(WhitePoint {
+                index,
+                white: CieXy::from_bits(x_hi, y_hi, lo & 0xf),
+                gamma: (gamma != 0xff).then_some((gamma as f32 + 100.0) / 100.0),
+            });
+        }
+        i = rest;
+    }
+    let (i, _padding) = take(3_usize).parse(i)?;
+    Ok((i, white_points))
+}
+
+/// cubic and quadratic coefficients of the display color management polynomial
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ColorManagementCurve {
+    pub a3: f32,
+    pub a2: f32,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ColorManagement {
+    pub version: u8,
+    pub red: ColorManagementCurve,
+    pub green: ColorManagementCurve,
+    pub blue: ColorManagementCurve,
+}
+
+fn parse_color_management(i: &[u8]) -> IResult<&[u8], ColorManagement> {
+    let (i, version) = le_u8.parse(i)?;
+    let (i, coefficients) = count(le_u16, 6)(i)?;
+    let curve = |n: usize| ColorManagementCurve {
+        a3: coefficients[n] as f32 / 100.0,
+        a2: coefficients[n + 1] as f32 / 100.0,
+    };
+    Ok((
+        i,
+        ColorManagement {
+            version,
+            red: curve(0),
+            green: curve(2),
+            blue: curve(4),
+        },
+    ))
+}
+
+/// CVT 3-byte timing code
+#[derive(Debug, PartialEq, Clone)]
+pub struct CvtTimingCode {
+    pub vertical_active_lines: u16,
+    pub aspect_ratio: AspectRatio,
+    /// preferred vertical refresh rate in Hz
+    pub preferred_refresh_rate: u8,
+    /// supported vertical refresh rates in Hz using standard blanking
+    pub refresh_rates: Vec<u8>,
+    /// 60 Hz with reduced blanking is supported
+    pub reduced_blanking: bool,
+}
+
+impl CvtTimingCode {
+    pub fn horizontal_active_pixels(&self) -> u16 {
+        let (width, height) = match self.aspect_ratio {
+            AspectRatio::Ratio16x9 => (16, 9),
+            AspectRatio::Ratio16x10 => (16, 10),
+            AspectRatio::Ratio15x9 => (15, 9),
+            _ => (4, 3),
+        };
+        // horizontal pixels are rounded down to a multiple of the 8 pixel cell size
+        (self.vertical_active_lines as u32 * width / height / 8 * 8) as u16
+    }
+}
+
+fn parse_cvt_timing_code(i: &[u8]) -> IResult<&[u8], Option<CvtTimingCode>> {
+    let (i, lines_lo) = le_u8.parse(i)?;
+    let (i, lines_hi_aspect) = le_u8.parse(i)?;
+    let (i, refresh) = le_u8.parse(i)?;
+    if lines_lo == 0 && lines_hi_aspect == 0 && refresh == 0 {
+        return Ok((i, None));
+    }
+    let aspect_ratio = match lines_hi_aspect >> 2 & 0x3 {
+        0b00 => AspectRatio::Ratio4x3,
+        0b01 => AspectRatio::Ratio16x9,
+        0b10 => AspectRatio::Ratio16x10,
+        _ => AspectRatio::Ratio15x9,
+    };
+    let rates = [50, 60, 75, 85];
+    Ok((
+        i,
+        Some(CvtTimingCode {
+            vertical_active_lines: (((lines_hi_aspect >> 4) as u16) << 8 | lines_lo as u16) * 2 + 2,
+            aspect_ratio,
+            preferred_refresh_rate: rates[(refresh >> 5 & 0x3) as usize],
+            refresh_rates: rates
+                .into_iter()
+                .enumerate()
+                .filter(|(bit, _)| refresh & (0x10 >> bit) != 0)
+                .map(|(_, rate)| rate)
+                .collect(),
+            reduced_blanking: refresh & 0x01 != 0,
+        }),
+    ))
+}
+
+fn parse_timing_codes(i: &[u8]) -> IResult<&[u8], Vec<CvtTimingCode>> {
+    let (i, _version) = le_u8.parse(i)?;
+    let (i, codes) = count(parse_cvt_timing_code, 4)(i)?;
+    Ok((i, codes.into_iter().flatten().collect()))
+}
+
+/// video modes of the established timings III bitmap starting with the most significant bit
+const ESTABLISHED_TIMING_III_MODES: [VideoMode; 44] = [
+    VideoMode::new(640, 350, 85),
+    VideoMode::new(640, 400, 85),
+    VideoMode::new(720, 400, 85),
+    VideoMode::new(640, 480, 85),
+    VideoMode::new(848, 480, 60),
+    VideoMode::new(800, 600, 85),
+    VideoMode::new(1024, 768, 85),
+    VideoMode::new(1152, 864, 75),
+    VideoMode::reduced_blanking(1280, 768, 60),
+    VideoMode::new(1280, 768, 60),
+    VideoMode::new(1280, 768, 75),
+    VideoMode::new(1280, 768, 85),
+    VideoMode::new(1280, 960, 60),
+    VideoMode::new(1280, 960, 85),
+    VideoMode::new(1280, 1024, 60),
+    VideoMode::new(1280, 1024, 85),
+    VideoMode::new(1360, 768, 60),
+    VideoMode::reduced_blanking(1440, 900, 60),
+    VideoMode::new(1440, 900, 60),
+    VideoMode::new(1440, 900, 75),
+    VideoMode::new(1440, 900, 85),
+    VideoMode::reduced_blanking(1400, 1050, 60),
+    VideoMode::new(1400, 1050, 60),
+    VideoMode::new(1400, 1050, 75),
+    VideoMode::new(1400, 1050, 85),
+    VideoMode::reduced_blanking(1680, 1050, 60),
+    VideoMode::new(1680, 1050, 60),
+    VideoMode::new(1680, 1050, 75),
+    VideoMode::new(1680, 1050, 85),
+    VideoMode::new(1600, 1200, 60),
+    VideoMode::new(1600, 1200, 65),
+    VideoMode::new(1600, 1200, 70),
+    VideoMode::new(1600, 1200, 75),
+    VideoMode::new(1600, 1200, 85),
+    VideoMode::new(1792, 1344, 60),
+    VideoMode::new(1792, 1344, 75),
+    VideoMode::new(1856, 1392, 60),
+    VideoMode::new(1856, 1392, 75),
+    VideoMode::reduced_blanking(1920, 1200, 60),
+    VideoMode::new(1920, 1200, 60),
+    VideoMode::new(1920, 1200, 75),
+    VideoMode::new(1920, 1200, 85),
+    VideoMode::new(1920, 1440, 60),
+    VideoMode::new(1920, 1440, 75),
+];
+
+/// Bitmap of the supported established timings III
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct EstablishedTimingsIII {
+    pub bitmap: [u8; 6],
+}
+
+impl EstablishedTimingsIII {
+    pub fn modes(&self) -> Vec<VideoMode> {
+        ESTABLISHED_TIMING_III_MODES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.bitmap[bit / 8] & (0x80 >> (bit % 8)) != 0)
+            .map(|(_, mode)| *mode)
+            .collect()
+    }
+}
+
+fn parse_established_timings_iii(i: &[u8]) -> IResult<&[u8], EstablishedTimingsIII> {
+    let (i, _version) = le_u8.parse(i)?;
+    let mut bitmap = [0; 6];
+    let (i, _) = fill(le_u8, &mut bitmap).parse(i)?;
+    let (i, _reserved) = take(6_usize).parse(i)?;
+    Ok((i, EstablishedTimingsIII { bitmap }))
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Descriptor {
+    DetailedTiming(DetailedTiming),
+    SerialNumber(String),
+    UnspecifiedText(String),
+    RangeLimits(RangeLimits),
+    ProductName(String),
+    WhitePoint(Vec<WhitePoint>),
+    StandardTiming(Vec<StandardTiming>),
+    ColorManagement(ColorManagement),
+    TimingCodes(Vec<CvtTimingCode>),
+    EstablishedTimings(EstablishedTimingsIII),
+    Dummy,
+    /// manufacturer specified (tag 0x00-0x0F) or unknown descriptor
+    Unknown {
+        tag: u8,
+        /// reserved byte following the tag
+        reserved: u8,
+        data: [u8; 13],
+    },
+}
+
+fn parse_descriptor(i: &[u8], version: (u8, u8)) -> IResult<&[u8], Descriptor> {
+    let (i, prefix) = peek(take(4_usize)).parse(i)?;
+    if prefix[0] == 0 && prefix[1] == 0 && prefix[2] == 0 {
+        // the descriptor type follows the zero prefix
+        let descriptor_type = prefix[3];
+        match descriptor_type {
+            0xFF => {
+                let (i, _reserved) = take(5_usize).parse(i)?;
+                let (i, s) = parse_descriptor_text(i)?;
+                Ok((i, Descriptor::SerialNumber(s)))
+            }
+            0xFE => {
+                let (i, _reserved) = take(5_usize).parse(i)?;
+                let (i, s) = parse_descriptor_text(i)?;
+                Ok((i, Descriptor::UnspecifiedText(s)))
+            }
+            0xFD => {
+                let (i, _reserved) = take(4_usize).parse(i)?;
+                let (i, offsets) = le_u8.parse(i)?;
+                let (i, limits) = parse_range_limits(i, offsets)?;
+                Ok((i, Descriptor::RangeLimits(limits)))
+            }
+            0xFC => {
+                let (i, _reserved) = take(5_usize).parse(i)?;
+                let (i, s) = parse_descriptor_text(i)?;
+                Ok((i, Descriptor::ProductName(s)))
+            }
+            0xFB => {
+                let (i, _reserved) = take(5_usize).parse(i)?;
+                let (i, white_points) = parse_white_points(i)?;
+                Ok((i, Descriptor::WhitePoint(white_points)))
+            }
+            0xFA => {
+                let (i, _reserved) = take(5_usize).parse(i)?;
+                let (i, timings) = count(|i| parse_standard_timing(i, version), 6)(i)?;
+                let (i, _padding) = take(1_usize).parse(i)?;
+                Ok((
+                    i,
+                    Descriptor::StandardTiming(timings.into_iter().flatten().collect()),
+                ))
+            }
+            0xF9 => {
+                let (i, _reserved) = take(5_usize).parse(i)?;
+                let (i, color_management) = parse_color_management(i)?;
+                Ok((i, Descriptor::ColorManagement(color_management)))
+            }
+            0xF8 => {
+                let (i, _reserved) = take(5_usize).parse(i)?;
+                let (i, codes) = parse_timing_codes(i)?;
+                Ok((i, Descriptor::TimingCodes(codes)))
+            }
+            0xF7 => {
+                let (i, _reserved) = take(5_usize).parse(i)?;
+                let (i, timings) = parse_established_timings_iii(i)?;
+                Ok((i, Descriptor::EstablishedTimings(timings)))
+            }
+            0x10 => {
+                let (i, _reserved) = take(5_usize).parse(i)?;
+                let (i, _data) = take(13_usize).parse(i)?;
+                Ok((i, Descriptor::Dummy))
+            }
+            _ => {
+                let (i, _prefix) = take(4_usize).parse(i)?;
+                let (i, reserved) = le_u8(i)?;
+                let mut data = [0; 13];
+                let (i, _) = fill(le_u8, &mut data).parse(i)?;
+                Ok((
+                    i,
+                    Descriptor::Unknown {
+                        tag: descriptor_type,
+                        reserved,
+                        data,
+                    },
+                ))
+            }
+        }
+    } else {
+        let (i, timing) = parse_detailed_timing(i)?;
+        Ok((i, Descriptor::DetailedTiming(timing)))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Edid {
+    pub header: Header,
+    pub display: Display,
+    pub chromaticity: Chromaticity,
+    pub established_timings: EstablishedTimings,
+    pub standard_timings: Vec<StandardTiming>,
+    pub descriptors: Vec<Descriptor>,
+    pub num_extr: u8,
+    /// raw extension blocks following the base block
+    pub extensions: Vec<[u8; EDID_BLOCK_LENGTH]>,
+}
+
+impl Edid {
+    /// serial number string of the display descriptors
+    pub fn serial_number(&self) -> Option<&str> {
+        self.descriptors
+            .iter()
+            .find_map(|descriptor| match descriptor {
+                Descriptor::SerialNumber(serial) => Some(serial.as_str()),
+                _ => None,
+            })
+    }
+
+    /// product name of the display descriptors
+    pub fn product_name(&self) -> Option<&str> {
+        self.descriptors
+            .iter()
+            .find_map(|descriptor| match descriptor {
+                Descriptor::ProductName(name) => Some(name.as_str()),
+                _ => None,
+            })
+    }
+
+    /// parse all CTA-861 extension blocks
+    pub fn cta861_extensions(&self) -> Result<Vec<cta861::Cta861Extension>, EdidParseError> {
+        self.extensions
+            .iter()
+            .filter(|extension| extension[0] == cta861::CTA861_EXTENSION_TAG)
+            .map(|extension| cta861::parse_cta861_extension(extension))
+            .collect()
+    }
+
+    /// parse all DisplayID sections embedded as extension blocks
+    pub fn display_id_extensions(&self) -> Result<Vec<DisplayId>, DisplayIdParseError> {
+        self.extensions
+            .iter()
+            .filter(|extension| extension[0] == DISPLAY_ID_EXTENSION_TAG)
+            .map(|extension| parse_display_id_extension(extension))
+            .collect()
+    }
+
+    /// DisplayID data embedded into the extension blocks, the data blocks of structures split
+    /// across multiple extension blocks are merged into the first structure
+    pub fn display_id(&self) -> Result<Option<DisplayId>, DisplayIdParseError> {
+        let mut sections = self.display_id_extensions()?.into_iter();
+        Ok(sections.next().map(|mut display_id| {
+            display_id
+                .data_blocks
+                .extend(sections.flat_map(|section| section.data_blocks));
+            display_id
+        }))
+    }
+}
+
+/// size of the edid base block and each extension block
+pub const EDID_BLOCK_LENGTH: usize = 128;
+
+fn block_checksum_valid(block: &[u8]) -> bool {
+    block.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) == 0
+}
+
+pub fn parse_edid(full_input: &[u8]) -> Result<Edid, EdidParseError> {
+    let edid = parse_edid_blocks(full_input)?;
+    if !full_input
+        .chunks_exact(EDID_BLOCK_LENGTH)
+        .take(1 + edid.extensions.len())
+        .all(block_checksum_valid)
+    {
+        return Err(EdidParseError::InvalidChecksum);
+    }
+    Ok(edid)
+}
+
+/// parse the base block and all available extension blocks without verifying checksums
+fn parse_edid_blocks(full_input: &[u8]) -> Result<Edid, EdidParseError> {
+    let (i, header) = parse_header(full_input)?;
+    let (i, display) = parse_display(i, (header.version, header.revision))?;
+    let (i, chromaticity) = parse_chromaticity(i)?;
+    let (i, established_timings) = parse_established_timings(i)?;
+    let (i, standard_timings) = parse_standard_timings(i, (header.version, header.revision))?;
+    let (i, descriptors) = count(
+        |i| parse_descriptor(i, (header.version, header.revision)),
+        4,
+    )(i)?;
+    let (i, num_extr) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?; // number of extensions
+    let (_i, _check) = le_u8::<&[u8], nom::error::Error<_>>.parse(i)?;
+    // extension blocks are only available if they have been read, data containing only the base
+    // block is still valid
+    let mut extensions = Vec::new();
+    for block in full_input[EDID_BLOCK_LENGTH..]
+        .chunks_exact(EDID_BLOCK_LENGTH)
+        .take(num_extr as usize)
+    {
+        let mut extension = [0; EDID_BLOCK_LENGTH];
+        extension.copy_from_slice(block);
+        extensions.push(extension);
+    }
+    Ok(Edid {
+        header,
+        display,
+        chromaticity,
+        established_timings,
+        standard_timings,
+        descriptors,
+        num_extr,
+        extensions,
+    })
+}
+
+/// Issues found while parsing edid data in lenient mode
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum EdidWarning {
+    /// block 0 is the base block
+    #[error("Block {block} has an invalid checksum 0x{checksum:02x}, expected 0x{expected:02x}")]
+    InvalidChecksum {
+        block: usize,
+        checksum: u8,
+        expected: u8,
+    },
+    #[error("Header bytes are invalid: {0:02x?}")]
+    InvalidHeader([u8; 8]),
+    #[error("Block {block} is truncated to {length} bytes")]
+    TruncatedBlock { block: usize, length: usize },
+    #[error("Only {found} of {expected} extension blocks are present")]
+    MissingExtensions { expected: u8, found: usize },
+    /// screen size in cm
+    #[error("Screen size {width}x{height} cm is invalid")]
+    InvalidScreenSize { width: u8, height: u8 },
+    /// image size of a detailed timing descriptor in mm
+    #[error("Detailed timing {descriptor} image size {width}x{height} mm exceeds the screen size")]
+    InvalidImageSize {
+        descriptor: usize,
+        width: u16,
+        height: u16,
+    },
+}
+
+/// parse edid data tolerating corrupted checksums, header bytes and truncated data
+///
+/// Adapters and KVM switches often corrupt single bytes of the edid while the remaining data is
+/// still usable. All issues are reported as warnings instead of rejecting the data, only empty
+/// input fails.
+pub fn parse_edid_lenient(full_input: &[u8]) -> Result<(Edid, Vec<EdidWarning>), EdidParseError> {
+    if full_input.is_empty() {
+        return Err(EdidParseError::Empty);
+    }
+    let mut warnings = Vec::new();
+    let mut data = full_input.to_vec();
+    if data.len() < EDID_BLOCK_LENGTH {
+        warnings.push(EdidWarning::TruncatedBlock {
+            block: 0,
+            length: data.len(),
+        });
+        data.resize(EDID_BLOCK_LENGTH, 0);
+    }
+    // checksums are verified against the received data, only the parsed copy is repaired
+    let mut repaired = data.clone();
+    if data[..EDID_HEADER.len()] != EDID_HEADER {
+        let mut header = [0; 8];
+        header.copy_from_slice(&data[..EDID_HEADER.len()]);
+        warnings.push(EdidWarning::InvalidHeader(header));
+        repaired[..EDID_HEADER.len()].copy_from_slice(&EDID_HEADER);
+    }
+
+    let edid = parse_edid_blocks(&repaired)?;
+    for (block, data) in data
+        .chunks_exact(EDID_BLOCK_LENGTH)
+        .take(1 + edid.extensions.len())
+        .enumerate()
+    {
+        let sum = data.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+        if sum != 0 {
+            let checksum = data[EDID_BLOCK_LENGTH - 1];
+            warnings.push(EdidWarning::InvalidChecksum {
+                block,
+                checksum,
+                expected: checksum.wrapping_sub(sum),
+            });
+        }
+    }
+    let remainder = (data.len() - EDID_BLOCK_LENGTH) % EDID_BLOCK_LENGTH;
+    if remainder != 0 && edid.extensions.len() < edid.num_extr as usize {
+        warnings.push(EdidWarning::TruncatedBlock {
+            block: 1 + edid.extensions.len(),
+            length: remainder,
+        });
+    }
+    if edid.extensions.len() < edid.num_extr as usize {
+        warnings.push(EdidWarning::MissingExtensions {
+            expected: edid.num_extr,
+            found: edid.extensions.len(),
+        });
+    }
+
+    let (width, height) = (edid.display.width, edid.display.height);
+    // a single zero dimension encodes the aspect ratio starting with edid 1.4
+    let aspect_ratio = (edid.header.version, edid.header.revision) >= (1, 4);
+    if (width == 0) != (height == 0) && !aspect_ratio {
+        warnings.push(EdidWarning::InvalidScreenSize { width, height });
+    }
+    if width != 0 && height != 0 {
+        for (descriptor, descriptor_data) in edid.descriptors.iter().enumerate() {
+            if let Descriptor::DetailedTiming(timing) = descriptor_data {
+                // allow up to 1 cm of rounding in the screen size
+                if timing.horizontal_size > (width as u16 + 1) * 10
+                    || timing.vertical_size > (height as u16 + 1) * 10
+                {
+                    warnings.push(EdidWarning::InvalidImageSize {
+                        descriptor,
+                        width: timing.horizontal_size,
+                        height: timing.vertical_size,
+                    });
+                }
+            }
+        }
+    }
+    Ok((edid, warnings))
+}
+
+#[derive(Error, Debug)]
+pub enum EdidParseError {
+    #[error("No edid data available")]
+    Empty,
+    #[error("Checksum is invalid, data corrupt!")]
+    InvalidChecksum,
+    #[error("Parsing data failed: {0}")]
+    NomParserError(String),
+    #[error("Extension block has an unexpected tag")]
+    UnexpectedExtension,
+}
+
+impl<T> From<nom::Err<T>> for EdidParseError
+where
+    T: core::fmt::Debug,
+{
+    fn from(value: nom::Err<T>) -> Self {
+        EdidParseError::NomParserError(format!("{value}"))
+    }
+}
+
+const CP437_FORWARD_TABLE: &[u16] = &[
+    0x0000, 0x263A, 0x263B, 0x2665, 0x2666, 0x2663, 0x2660, 0x2022, 0x25D8, 0x25CB, 0x25D9, 0x2642,
+    0x2640, 0x266A, 0x266B, 0x263C, 0x25BA, 0x25C4, 0x2195, 0x203C, 0x00B6, 0x00A7, 0x25AC, 0x21A8,
+    0x2191, 0x2193, 0x2192, 0x2190, 0x221F, 0x2194, 0x25B2, 0x25BC, 0x0020, 0x0021, 0x0022, 0x0023,
+    0x0024, 0x0025, 0x0026, 0x0027, 0x0028, 0x0029, 0x002A, 0x002B, 0x002C, 0x002D, 0x002E, 0x002F,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x003A, 0x003B,
+    0x003C, 0x003D, 0x003E, 0x003F, 0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052, 0x0053,
+    0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A, 0x005B, 0x005C, 0x005D, 0x005E, 0x005F,
+    0x0060, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006A, 0x006B,
+    0x006C, 0x006D, 0x006E, 0x006F, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007A, 0x007B, 0x007C, 0x007D, 0x007E, 0x2302, 0x00C7, 0x00FC, 0x00E9, 0x00E2,
+    0x00E4, 0x00E0, 0x00E5, 0x00E7, 0x00EA, 0x00EB, 0x00E8, 0x00EF, 0x00EE, 0x00EC, 0x00C4, 0x00C5,
+    0x00C9, 0x00E6, 0x00C6, 0x00F4, 0x00F6, 0x00F2, 0x00FB, 0x00F9, 0x00FF, 0x00D6, 0x00DC, 0x00A2,
+    0x00A3, 0x00A5, 0x20A7, 0x0192, 0x00E1, 0x00ED, 0x00F3, 0x00FA, 0x00F1, 0x00D1, 0x00AA, 0x00BA,
+    0x00BF, 0x2310, 0x00AC, 0x00BD, 0x00BC, 0x00A1, 0x00AB, 0x00BB, 0x2591, 0x2592, 0x2593, 0x2502,
+    0x2524, 0x2561, 0x2562, 0x2556, 0x2555, 0x2563, 0x2551, 0x2557, 0x255D, 0x255C, 0x255B, 0x2510,
+    0x2514, 0x2534, 0x252C, 0x251C, 0x2500, 0x253C, 0x255E, 0x255F, 0x255A, 0x2554, 0x2569, 0x2566,
+    0x2560, 0x2550, 0x256C, 0x2567, 0x2568, 0x2564, 0x2565, 0x2559, 0x2558, 0x2552, 0x2553, 0x256B,
+    0x256A, 0x2518, 0x250C, 0x2588, 0x2584, 0x258C, 0x2590, 0x2580, 0x03B1, 0x00DF, 0x0393, 0x03C0,
+    0x03A3, 0x03C3, 0x00B5, 0x03C4, 0x03A6, 0x0398, 0x03A9, 0x03B4, 0x221E, 0x03C6, 0x03B5, 0x2229,
+    0x2261, 0x00B1, 0x2265, 0x2264, 0x2320, 0x2321, 0x00F7, 0x2248, 0x00B0, 0x2219, 0x00B7, 0x221A,
+    0x207F, 0x00B2, 0x25A0, 0x00A0,
+];
+
+pub fn cp437_forward(code: u8) -> char {
+    char::from_u32(CP437_FORWARD_TABLE[code as usize] as u32).unwrap()
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::{
+        parse_edid, parse_edid_lenient, parse_timing_codes, AspectRatio, ColorEncoding,
+        ColorSupport, Descriptor, DigitalInterface, EdidBuildError, EdidBuilder, EdidWarning,
+        RangeLimits, StandardTiming, TimingFormula, VideoInput, VideoMode,
+    };
+
+    pub(crate) const EDID_BASE_BLOCK: [u8; 128] = [
+        0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x10, 0xac, 0xc4, 0xa0, 0x4c, 0x4c, 0x4c,
+        0x4c, 0x01, 0x1c, 0x01, 0x04, 0xa5, 0x34, 0x20, 0x78, 0x3a, 0xee, 0x91, 0xa3, 0x54, 0x4c,
+        0x99, 0x26, 0x0f, 0x50, 0x54, 0x21, 0x08, 0x00, 0xd1, 0x00, 0xd1, 0xc0, 0x81, 0x80, 0xb3,
+        0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x28, 0x3c, 0x80, 0xa0, 0x70, 0xb0,
+        0x23, 0x40, 0x30, 0x20, 0x36, 0x00, 0x06, 0x44, 0x21, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00,
+        0xff, 0x00, 0x41, 0x42, 0x43, 0x31, 0x32, 0x33, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x00, 0x00, 0x00, 0xfd, 0x00, 0x38, 0x4c, 0x1e, 0x51, 0x11, 0x00, 0x0a, 0x20, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x00, 0x00, 0x00, 0xfc, 0x00, 0x44, 0x45, 0x4c, 0x4c, 0x20, 0x55, 0x32,
+        0x34, 0x31, 0x35, 0x0a, 0x20, 0x20, 0x00, 0xc5,
+    ];
+
+    #[test]
+    fn parse_base_block() {
+        let edid = parse_edid(&EDID_BASE_BLOCK).unwrap();
+        assert_eq!(edid.header.vendor, ['D', 'E', 'L']);
+        assert_eq!(edid.header.product, 0xa0c4);
+
+        assert_eq!(
+            edid.display.video_input,
+            VideoInput::Digital {
+                bit_depth: Some(8),
+                interface: DigitalInterface::DisplayPort
+            }
+        );
+        assert_eq!(edid.display.gamma, Some(2.2));
+        let features = edid.display.features;
+        assert!(!features.dpms_standby && !features.dpms_suspend && features.dpms_active_off);
+        assert_eq!(
+            features.color,
+            ColorSupport::Encoding(ColorEncoding::Rgb444YCrCb444YCrCb422)
+        );
+        assert!(!features.srgb_default && features.preferred_timing_native);
+
+        let chromaticity = edid.chromaticity;
+        assert_eq!((chromaticity.red.x * 1024.0).round(), 655.0);
+        assert_eq!((chromaticity.white.y * 1024.0).round(), 337.0);
+        assert!((chromaticity.green.y - 0.6).abs() < 0.001);
+
+        assert_eq!(
+            edid.established_timings.modes(),
+            vec![
+                VideoMode::new(640, 480, 60),
+                VideoMode::new(800, 600, 60),
+                VideoMode::new(1024, 768, 60)
+            ]
+        );
+
+        assert_eq!(edid.standard_timings.len(), 4);
+        assert_eq!(
+            edid.standard_timings[0].aspect_ratio,
+            AspectRatio::Ratio16x10
+        );
+        assert_eq!(edid.standard_timings[0].vertical_active_lines(), 1200);
+        assert_eq!(edid.standard_timings[1].horizontal_active_pixels, 1920);
+        assert_eq!(edid.standard_timings[1].vertical_active_lines(), 1080);
+        assert_eq!(edid.standard_timings[2].aspect_ratio, AspectRatio::Ratio5x4);
+        assert_eq!(edid.standard_timings[2].refresh_rate, 60);
+    }
+
+    #[test]
+    fn parse_display_descriptors() {
+        let edid = parse_edid(&EDID_BASE_BLOCK).unwrap();
+        assert!(matches!(edid.descriptors[0], Descriptor::DetailedTiming(_)));
+        assert_eq!(
+            edid.descriptors[1],
+            Descriptor::SerialNumber("ABC123".to_string())
+        );
+        assert_eq!(
+            edid.descriptors[2],
+            Descriptor::RangeLimits(RangeLimits {
+                min_vertical_rate: 56,
+                max_vertical_rate: 76,
+                min_horizontal_rate: 30,
+                max_horizontal_rate: 81,
+                max_pixel_clock: Some(170),
+                timing_formula: TimingFormula::DefaultGtf,
+            })
+        );
+        assert_eq!(
+            edid.descriptors[3],
+            Descriptor::ProductName("DELL U2415".to_string())
+        );
+
+        let (_, codes) = parse_timing_codes(&[
+            0x01, 0x1b, 0x24, 0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ])
+        .unwrap();
+        assert_eq!(codes.len(), 1);
+        assert_eq!(codes[0].vertical_active_lines, 1080);
+        assert_eq!(codes[0].horizontal_active_pixels(), 1920);
+        assert_eq!(codes[0].preferred_refresh_rate, 60);
+        assert_eq!(codes[0].refresh_rates, vec![60]);
+    }
+
+    #[test]
+    fn parse_lenient() {
+        assert!(parse_edid(&[]).is_err());
+        assert!(parse_edid_lenient(&[]).is_err());
+
+        let (edid, warnings) = parse_edid_lenient(&EDID_BASE_BLOCK).unwrap();
+        assert_eq!(edid, parse_edid(&EDID_BASE_BLOCK).unwrap());
+        assert!(warnings.is_empty());
+
+        let mut corrupt = EDID_BASE_BLOCK;
+        corrupt[0] = 0x01;
+        corrupt[127] = 0x00;
+        assert!(parse_edid(&corrupt).is_err());
+        let (lenient, warnings) = parse_edid_lenient(&corrupt).unwrap();
+        assert_eq!(lenient, edid);
+        assert_eq!(
+            warnings,
+            vec![
+                EdidWarning::InvalidHeader([0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00]),
+                EdidWarning::InvalidChecksum {
+                    block: 0,
+                    checksum: 0x00,
+                    expected: 0xc4,
+                },
+            ]
+        );
+
+        let (_, warnings) = parse_edid_lenient(&EDID_BASE_BLOCK[..100]).unwrap();
+        assert_eq!(
+            warnings[0],
+            EdidWarning::TruncatedBlock {
+                block: 0,
+                length: 100
+            }
+        );
+    }
+
+    #[test]
+    fn edid_round_trip() {
+        let edid = parse_edid(&EDID_BASE_BLOCK).unwrap();
+        assert_eq!(edid.to_bytes(), EDID_BASE_BLOCK);
+
+        let edid = EdidBuilder::new()
+            .vendor(['T', 'S', 'T'])
+            .product(0x0102)
+            .serial(42)
+            .manufacture_date(12, 2024)
+            .screen_size(60, 34)
+            .standard_timing(StandardTiming {
+                horizontal_active_pixels: 2560,
+                aspect_ratio: AspectRatio::Ratio16x9,
+                refresh_rate: 120,
+            })
+            .descriptor(Descriptor::RangeLimits(RangeLimits {
+                min_vertical_rate: 48,
+                max_vertical_rate: 300,
+                min_horizontal_rate: 30,
+                max_horizontal_rate: 320,
+                max_pixel_clock: Some(1000),
+                timing_formula: TimingFormula::RangeLimitsOnly,
+            }))
+            .descriptor(Descriptor::ProductName("Test Display".to_string()))
+            .extension([0x02; 128])
+            .build()
+            .unwrap();
+        assert_eq!(edid.header.year, 34);
+        assert_eq!(edid.descriptors.len(), 4);
+        assert_eq!(edid.extensions.len(), 1);
+        assert_eq!(parse_edid(&edid.to_bytes()).unwrap(), edid);
+    }
+
+    #[test]
+    fn manufacturer_descriptor_round_trip() {
+        let descriptor = Descriptor::Unknown {
+            tag: 0x0F,
+            reserved: 0x01,
+            data: [0x5A; 13],
+        };
+        let edid = EdidBuilder::new()
+            .descriptor(descriptor.clone())
+            .build()
+            .unwrap();
+        assert_eq!(edid.descriptors[0], descriptor);
+        let data = edid.to_bytes();
+        assert_eq!(data[54..59], [0x00, 0x00, 0x00, 0x0F, 0x01]);
+        assert_eq!(parse_edid(&data).unwrap().to_bytes(), data);
+
+        let builder = (0..5).fold(EdidBuilder::new(), |builder, _| {
+            builder.descriptor(descriptor.clone())
+        });
+        assert!(matches!(
+            builder.build(),
+            Err(EdidBuildError::TooManyDescriptors)
+        ));
+    }
+
+    #[test]
+    fn extension_count_follows_extensions() {
+        let mut edid = EdidBuilder::new().extension([0x02; 128]).build().unwrap();
+        edid.num_extr = 3;
+        let data = edid.to_bytes();
+        assert_eq!(data.len(), 256);
+        assert_eq!(data[126], 1);
+        assert_eq!(parse_edid(&data).unwrap().num_extr, 1);
+    }
+}
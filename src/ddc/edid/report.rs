@@ -0,0 +1,1399 @@
+//! Human readable edid report using the layout and wording of the `edid-decode` tool
+
+use std::fmt::{self, Display, Formatter};
+
+use super::cta861::{
+    parse_cta861_extension, AudioFormat, Cta861Extension, DataBlock as CtaDataBlock, Speaker,
+    CTA861_EXTENSION_TAG,
+};
+use super::{
+    parse_edid_blocks, AnalogSignalLevel, AspectRatio, ColorEncoding, ColorSupport, Descriptor,
+    DetailedTiming, DigitalInterface, DisplayColorType, Edid, EdidParseError, RangeLimits,
+    TimingFormula, VideoInput, VideoMode, EDID_BLOCK_LENGTH,
+};
+use crate::ddc::display_id::{
+    parse_display_id_extension, DataBlock as DisplayIdDataBlock, DisplayId, ManufacturerId,
+    DISPLAY_ID_EXTENSION_TAG,
+};
+
+const SEPARATOR: &str = "----------------";
+
+/// Report of edid data including checksum status and conformity issues
+///
+/// The report is created from raw data, so edids with invalid checksums can still be inspected.
+///
+/// ```rust,no_run
+/// use libmonitor::ddc::edid::report::EdidReport;
+///
+/// let data = std::fs::read("/sys/class/drm/card0-DP-1/edid").unwrap();
+/// let report = EdidReport::new(&data).unwrap();
+/// println!("{report}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EdidReport {
+    edid: Edid,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Severity {
+    Warning,
+    Failure,
+}
+
+#[derive(Debug, Clone)]
+struct Issue {
+    block: usize,
+    severity: Severity,
+    message: String,
+}
+
+impl EdidReport {
+    /// parse the base block and all available extension blocks, checksum errors are reported
+    /// instead of rejecting the data
+    pub fn new(data: &[u8]) -> Result<Self, EdidParseError> {
+        let edid = parse_edid_blocks(data)?;
+        let length = EDID_BLOCK_LENGTH * (1 + edid.extensions.len());
+        Ok(Self {
+            edid,
+            data: data[..length].to_vec(),
+        })
+    }
+
+    pub fn edid(&self) -> &Edid {
+        &self.edid
+    }
+
+    /// conformity warnings in the format `Block <n>, <name>: <message>`
+    pub fn warnings(&self) -> Vec<String> {
+        self.messages(Severity::Warning)
+    }
+
+    /// conformity failures in the format `Block <n>, <name>: <message>`
+    pub fn failures(&self) -> Vec<String> {
+        self.messages(Severity::Failure)
+    }
+
+    /// the edid passes all conformity checks, warnings are allowed
+    pub fn conformant(&self) -> bool {
+        self.failures().is_empty()
+    }
+
+    fn messages(&self, severity: Severity) -> Vec<String> {
+        self.issues()
+            .into_iter()
+            .filter(|issue| issue.severity == severity)
+            .map(|issue| {
+                format!(
+                    "Block {}, {}: {}",
+                    issue.block,
+                    self.block_name(issue.block),
+                    issue.message
+                )
+            })
+            .collect()
+    }
+
+    fn block(&self, block: usize) -> &[u8] {
+        &self.data[block * EDID_BLOCK_LENGTH..(block + 1) * EDID_BLOCK_LENGTH]
+    }
+
+    fn block_name(&self, block: usize) -> String {
+        if block == 0 {
+            return "Base EDID".to_string();
+        }
+        match self.block(block)[0] {
+            CTA861_EXTENSION_TAG => "CTA-861 Extension Block".to_string(),
+            0x10 => "Video Timing Extension Block".to_string(),
+            0x40 => "Display Information Extension Block".to_string(),
+            0x50 => "Localized String Extension Block".to_string(),
+            DISPLAY_ID_EXTENSION_TAG => "DisplayID Extension Block".to_string(),
+            0xF0 => "Block Map Extension Block".to_string(),
+            0xFF => "Manufacturer-Specific Extension Block".to_string(),
+            tag => format!("Unknown EDID Extension Block 0x{tag:02x}"),
+        }
+    }
+
+    fn issues(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let mut issue = |block: usize, severity: Severity, message: String| {
+            issues.push(Issue {
+                block,
+                severity,
+                message,
+            })
+        };
+        for block in 0..self.data.len() / EDID_BLOCK_LENGTH {
+            let (checksum, expected) = checksum(self.block(block));
+            if checksum != expected {
+                issue(
+                    block,
+                    Severity::Failure,
+                    format!("Invalid checksum 0x{checksum:02x} (should be 0x{expected:02x})."),
+                );
+            }
+        }
+
+        let edid = &self.edid;
+        let header = &edid.header;
+        let version = (header.version, header.revision);
+        if header.version != 1 {
+            issue(
+                0,
+                Severity::Failure,
+                format!("Unknown EDID version {}.", header.version),
+            );
+        } else if header.revision > 4 {
+            issue(
+                0,
+                Severity::Warning,
+                format!("Unknown EDID minor version {}.", header.revision),
+            );
+        }
+        if !header.vendor.iter().all(char::is_ascii_uppercase) {
+            issue(
+                0,
+                Severity::Failure,
+                "Manufacturer name field contains garbage.".to_string(),
+            );
+        }
+        if header.week > 54 && header.week != 0xff {
+            issue(
+                0,
+                Severity::Failure,
+                format!("Invalid week {} of manufacture.", header.week),
+            );
+        }
+        if version >= (1, 3)
+            && !matches!(
+                edid.descriptors.first(),
+                Some(Descriptor::DetailedTiming(_))
+            )
+        {
+            issue(
+                0,
+                Severity::Failure,
+                "First descriptor must be a Detailed Timing Descriptor.".to_string(),
+            );
+        }
+        if version >= (1, 3)
+            && !edid
+                .descriptors
+                .iter()
+                .any(|descriptor| matches!(descriptor, Descriptor::ProductName(_)))
+        {
+            issue(
+                0,
+                Severity::Warning,
+                "Missing Display Product Name.".to_string(),
+            );
+        }
+        let range_limits = edid
+            .descriptors
+            .iter()
+            .find_map(|descriptor| match descriptor {
+                Descriptor::RangeLimits(limits) => Some(limits),
+                _ => None,
+            });
+        match range_limits {
+            Some(limits) => {
+                if limits.min_vertical_rate > limits.max_vertical_rate {
+                    issue(
+                        0,
+                        Severity::Failure,
+                        "Display Range Limits: Min vertical rate > max vertical rate.".to_string(),
+                    );
+                }
+                if limits.min_horizontal_rate > limits.max_horizontal_rate {
+                    issue(
+                        0,
+                        Severity::Failure,
+                        "Display Range Limits: Min horizontal freq > max horizontal freq."
+                            .to_string(),
+                    );
+                }
+            }
+            None if version >= (1, 4) && edid.display.features.continuous_frequency => issue(
+                0,
+                Severity::Failure,
+                "Display is continuous frequency, but no Display Range Limits Descriptor is present."
+                    .to_string(),
+            ),
+            None => {}
+        }
+        if (edid.num_extr as usize) > edid.extensions.len() {
+            issue(
+                0,
+                Severity::Warning,
+                format!(
+                    "Only {} of {} extension blocks are present.",
+                    edid.extensions.len(),
+                    edid.num_extr
+                ),
+            );
+        }
+
+        for (n, extension) in edid.extensions.iter().enumerate() {
+            let result = match extension[0] {
+                CTA861_EXTENSION_TAG => parse_cta861_extension(extension)
+                    .err()
+                    .map(|err| err.to_string()),
+                DISPLAY_ID_EXTENSION_TAG => parse_display_id_extension(extension)
+                    .err()
+                    .map(|err| err.to_string()),
+                _ => None,
+            };
+            if let Some(err) = result {
+                issue(n + 1, Severity::Failure, format!("{err}."));
+            }
+        }
+        issues
+    }
+}
+
+impl From<&Edid> for EdidReport {
+    /// create a report from an already parsed edid, the data is serialized again
+    fn from(edid: &Edid) -> Self {
+        Self {
+            edid: edid.clone(),
+            data: edid.to_bytes(),
+        }
+    }
+}
+
+/// checksum byte of the block and the value it should have
+fn checksum(block: &[u8]) -> (u8, u8) {
+    let (data, checksum) = block.split_at(block.len() - 1);
+    let sum = data.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    (checksum[0], 0u8.wrapping_sub(sum))
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn ratio(width: u32, height: u32) -> String {
+    match gcd(width, height) {
+        0 => "0:0".to_string(),
+        divisor => format!("{}:{}", width / divisor, height / divisor),
+    }
+}
+
+fn aspect_ratio(aspect_ratio: &AspectRatio) -> &'static str {
+    match aspect_ratio {
+        AspectRatio::Ratio1x1 => "1:1",
+        AspectRatio::Ratio16x10 => "16:10",
+        AspectRatio::Ratio4x3 => "4:3",
+        AspectRatio::Ratio5x4 => "5:4",
+        AspectRatio::Ratio16x9 => "16:9",
+        AspectRatio::Ratio15x9 => "15:9",
+    }
+}
+
+fn write_video_mode(f: &mut Formatter<'_>, indent: &str, mode: &VideoMode) -> fmt::Result {
+    let height = format!("{}{}", mode.height, if mode.interlaced { "i" } else { "" });
+    write!(
+        f,
+        "{indent}{:>5}x{:<5} {:>3} Hz",
+        mode.width, height, mode.refresh_rate
+    )?;
+    if mode.reduced_blanking {
+        write!(f, " (RB)")?;
+    }
+    writeln!(f)
+}
+
+fn write_detailed_timing(
+    f: &mut Formatter<'_>,
+    label: &str,
+    timing: &DetailedTiming,
+) -> fmt::Result {
+    let horizontal_total =
+        timing.horizontal_active_pixels as u32 + timing.horizontal_blanking_pixels as u32;
+    let vertical_total =
+        timing.vertical_active_lines as u32 + timing.vertical_blanking_lines as u32;
+    let refresh_rate = if horizontal_total * vertical_total == 0 {
+        0.0
+    } else {
+        timing.pixel_clock as f64 * 1000.0 / (horizontal_total * vertical_total) as f64
+    };
+    let horizontal_frequency = if horizontal_total == 0 {
+        0.0
+    } else {
+        timing.pixel_clock as f64 / horizontal_total as f64
+    };
+    let interlaced = timing.features & 0x80 != 0;
+    let height = format!(
+        "{}{}",
+        timing.vertical_active_lines,
+        if interlaced { "i" } else { "" }
+    );
+    writeln!(
+        f,
+        "    {label}: {:>5}x{:<5} {:>10.6} Hz {:>5}  {:>8.3} kHz {:>11.6} MHz ({} mm x {} mm)",
+        timing.horizontal_active_pixels,
+        height,
+        refresh_rate,
+        ratio(
+            timing.horizontal_active_pixels as u32,
+            timing.vertical_active_lines as u32
+        ),
+        horizontal_frequency,
+        timing.pixel_clock as f64 / 1000.0,
+        timing.horizontal_size,
+        timing.vertical_size,
+    )?;
+    let horizontal_back_porch = timing
+        .horizontal_blanking_pixels
+        .saturating_sub(timing.horizontal_front_porch + timing.horizontal_sync_width);
+    let vertical_back_porch = timing
+        .vertical_blanking_lines
+        .saturating_sub(timing.vertical_front_porch + timing.vertical_sync_width);
+    // sync polarities are only defined for digital separate sync
+    let polarity = |bit: u8| {
+        if timing.features & 0x18 != 0x18 {
+            String::new()
+        } else if timing.features & bit != 0 {
+            " Hpol P".to_string()
+        } else {
+            " Hpol N".to_string()
+        }
+    };
+    writeln!(
+        f,
+        "                 Hfront {:>4} Hsync {:>3} Hback {:>4}{}",
+        timing.horizontal_front_porch,
+        timing.horizontal_sync_width,
+        horizontal_back_porch,
+        polarity(0x02)
+    )?;
+    writeln!(
+        f,
+        "                 Vfront {:>4} Vsync {:>3} Vback {:>4}{}",
+        timing.vertical_front_porch,
+        timing.vertical_sync_width,
+        vertical_back_porch,
+        polarity(0x04).replace("Hpol", "Vpol")
+    )?;
+    if timing.horizontal_border_pixels != 0 || timing.vertical_border_pixels != 0 {
+        writeln!(
+            f,
+            "                 Hborder {} Vborder {}",
+            timing.horizontal_border_pixels, timing.vertical_border_pixels
+        )?;
+    }
+    Ok(())
+}
+
+fn write_range_limits(f: &mut Formatter<'_>, limits: &RangeLimits) -> fmt::Result {
+    let formula = match &limits.timing_formula {
+        TimingFormula::DefaultGtf => "GTF",
+        TimingFormula::RangeLimitsOnly => "Bare Limits",
+        TimingFormula::SecondaryGtf(_) => "Secondary GTF",
+        TimingFormula::Cvt(_) => "CVT",
+        TimingFormula::Reserved(_) => "Reserved",
+    };
+    writeln!(f, "    Display Range Limits:")?;
+    write!(
+        f,
+        "      Monitor ranges ({formula}): {}-{} Hz V, {}-{} kHz H",
+        limits.min_vertical_rate,
+        limits.max_vertical_rate,
+        limits.min_horizontal_rate,
+        limits.max_horizontal_rate
+    )?;
+    match limits.max_pixel_clock {
+        Some(clock) => writeln!(f, ", max dotclock {clock} MHz")?,
+        None => writeln!(f)?,
+    }
+    match &limits.timing_formula {
+        TimingFormula::SecondaryGtf(gtf) => {
+            writeln!(f, "      GTF Secondary Curve Block:")?;
+            writeln!(f, "        Start frequency: {} kHz", gtf.start_frequency)?;
+            writeln!(f, "        C: {:.1}%", gtf.c)?;
+            writeln!(f, "        M: {}%/kHz", gtf.m)?;
+            writeln!(f, "        K: {}", gtf.k)?;
+            writeln!(f, "        J: {:.1}%", gtf.j)?;
+        }
+        TimingFormula::Cvt(cvt) => {
+            writeln!(f, "      CVT version {}.{}", cvt.version, cvt.revision)?;
+            if let Some(pixels) = cvt.max_active_pixels {
+                writeln!(f, "      Max active pixels per line: {pixels}")?;
+            }
+            let ratios = cvt
+                .aspect_ratios
+                .iter()
+                .map(aspect_ratio)
+                .collect::<Vec<_>>();
+            writeln!(f, "      Supported aspect ratios: {}", ratios.join(" "))?;
+            if let Some(preferred) = &cvt.preferred_aspect_ratio {
+                writeln!(
+                    f,
+                    "      Preferred aspect ratio: {}",
+                    aspect_ratio(preferred)
+                )?;
+            }
+            if cvt.standard_blanking {
+                writeln!(f, "      Supports CVT standard blanking")?;
+            }
+            if cvt.reduced_blanking {
+                writeln!(f, "      Supports CVT reduced blanking")?;
+            }
+            if cvt.scaling != 0 {
+                writeln!(f, "      Supported display scaling: 0x{:x}", cvt.scaling)?;
+            }
+            writeln!(
+                f,
+                "      Preferred vertical refresh: {} Hz",
+                cvt.preferred_refresh_rate
+            )?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn write_descriptor(f: &mut Formatter<'_>, index: usize, descriptor: &Descriptor) -> fmt::Result {
+    match descriptor {
+        Descriptor::DetailedTiming(timing) => {
+            write_detailed_timing(f, &format!("DTD {}", index + 1), timing)
+        }
+        Descriptor::SerialNumber(serial) => {
+            writeln!(f, "    Display Product Serial Number: '{serial}'")
+        }
+        Descriptor::UnspecifiedText(text) => {
+            writeln!(f, "    Alphanumeric Data String: '{text}'")
+        }
+        Descriptor::RangeLimits(limits) => write_range_limits(f, limits),
+        Descriptor::ProductName(name) => writeln!(f, "    Display Product Name: '{name}'"),
+        Descriptor::WhitePoint(white_points) => {
+            writeln!(f, "    Color Point Data:")?;
+            for point in white_points {
+                write!(
+                    f,
+                    "      Index: {} White: {:.4}, {:.4}",
+                    point.index, point.white.x, point.white.y
+                )?;
+                match point.gamma {
+                    Some(gamma) => writeln!(f, " Gamma: {gamma:.2}")?,
+                    None => writeln!(f, " Gamma: is defined in an extension block")?,
+                }
+            }
+            Ok(())
+        }
+        Descriptor::StandardTiming(timings) => {
+            writeln!(f, "    Standard Timing Identifications:")?;
+            for timing in timings {
+                writeln!(
+                    f,
+                    "      {:>5}x{:<5} {:>3} Hz {:>5}",
+                    timing.horizontal_active_pixels,
+                    timing.vertical_active_lines(),
+                    timing.refresh_rate,
+                    aspect_ratio(&timing.aspect_ratio)
+                )?;
+            }
+            Ok(())
+        }
+        Descriptor::ColorManagement(color_management) => {
+            writeln!(f, "    Display Color Management Data:")?;
+            writeln!(f, "      Version:  {}", color_management.version)?;
+            for (name, curve) in [
+                ("Red", &color_management.red),
+                ("Green", &color_management.green),
+                ("Blue", &color_management.blue),
+            ] {
+                writeln!(f, "      {name} a3: {:.2}", curve.a3)?;
+                writeln!(f, "      {name} a2: {:.2}", curve.a2)?;
+            }
+            Ok(())
+        }
+        Descriptor::TimingCodes(codes) => {
+            writeln!(f, "    CVT 3 Byte Code Descriptor:")?;
+            for code in codes {
+                for rate in code.refresh_rates.iter() {
+                    write!(
+                        f,
+                        "      {:>5}x{:<5} {:>3} Hz {:>5}",
+                        code.horizontal_active_pixels(),
+                        code.vertical_active_lines,
+                        rate,
+                        aspect_ratio(&code.aspect_ratio)
+                    )?;
+                    if *rate == code.preferred_refresh_rate {
+                        write!(f, " (preferred vertical rate)")?;
+                    }
+                    writeln!(f)?;
+                }
+                if code.reduced_blanking {
+                    writeln!(
+                        f,
+                        "      {:>5}x{:<5}  60 Hz {:>5} (RB)",
+                        code.horizontal_active_pixels(),
+                        code.vertical_active_lines,
+                        aspect_ratio(&code.aspect_ratio)
+                    )?;
+                }
+            }
+            Ok(())
+        }
+        Descriptor::EstablishedTimings(timings) => {
+            writeln!(f, "    Established timings III:")?;
+            for mode in timings.modes() {
+                write_video_mode(f, "      ", &mode)?;
+            }
+            Ok(())
+        }
+        Descriptor::Dummy => writeln!(f, "    Dummy Descriptor:"),
+        Descriptor::Unknown { tag, data, .. } => {
+            if *tag <= 0x0F {
+                writeln!(
+                    f,
+                    "    Manufacturer-Specified Display Descriptor (0x{tag:02x}):"
+                )?;
+            } else {
+                writeln!(f, "    Unknown Display Descriptor (0x{tag:02x}):")?;
+            }
+            write_hex(f, "      ", data)
+        }
+    }
+}
+
+fn write_hex(f: &mut Formatter<'_>, indent: &str, data: &[u8]) -> fmt::Result {
+    for line in data.chunks(16) {
+        let bytes = line
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<Vec<_>>();
+        writeln!(f, "{indent}{}", bytes.join(" "))?;
+    }
+    Ok(())
+}
+
+fn write_base_block(f: &mut Formatter<'_>, edid: &Edid) -> fmt::Result {
+    let header = &edid.header;
+    let version = (header.version, header.revision);
+    writeln!(
+        f,
+        "  EDID Structure Version & Revision: {}.{}",
+        header.version, header.revision
+    )?;
+    writeln!(f, "  Vendor & Product Identification:")?;
+    writeln!(
+        f,
+        "    Manufacturer: {}",
+        header.vendor.iter().collect::<String>()
+    )?;
+    writeln!(f, "    Model: {}", header.product)?;
+    if header.serial != 0 {
+        writeln!(
+            f,
+            "    Serial Number: {} (0x{:08x})",
+            header.serial, header.serial
+        )?;
+    }
+    let year = header.year as u16 + 1990;
+    match header.week {
+        0xff => writeln!(f, "    Model year: {year}")?,
+        0 => writeln!(f, "    Made in: {year}")?,
+        week => writeln!(f, "    Made in: week {week} of {year}")?,
+    }
+
+    let display = &edid.display;
+    writeln!(f, "  Basic Display Parameters & Features:")?;
+    match display.video_input {
+        VideoInput::Digital {
+            bit_depth,
+            interface,
+        } => {
+            writeln!(f, "    Digital display")?;
+            if version >= (1, 4) {
+                match bit_depth {
+                    Some(depth) => writeln!(f, "    Bits per primary color channel: {depth}")?,
+                    None => writeln!(f, "    Color depth is undefined")?,
+                }
+                match interface {
+                    DigitalInterface::Undefined => {
+                        writeln!(f, "    Digital interface is not defined")?
+                    }
+                    DigitalInterface::Dvi => writeln!(f, "    DVI interface")?,
+                    DigitalInterface::HdmiA => writeln!(f, "    HDMI-a interface")?,
+                    DigitalInterface::HdmiB => writeln!(f, "    HDMI-b interface")?,
+                    DigitalInterface::Mddi => writeln!(f, "    MDDI interface")?,
+                    DigitalInterface::DisplayPort => writeln!(f, "    DisplayPort interface")?,
+                    DigitalInterface::Reserved(value) => {
+                        writeln!(f, "    Unknown interface: 0x{value:x}")?
+                    }
+                }
+            }
+        }
+        VideoInput::Analog {
+            signal_level,
+            blank_to_black_setup,
+            separate_sync,
+            composite_sync,
+            sync_on_green,
+            serrated_vsync,
+        } => {
+            writeln!(f, "    Analog display")?;
+            let level = match signal_level {
+                AnalogSignalLevel::Level0700_0300 => "0.700 : 0.300 : 1.000",
+                AnalogSignalLevel::Level0714_0286 => "0.714 : 0.286 : 1.000",
+                AnalogSignalLevel::Level1000_0400 => "1.000 : 0.400 : 1.400",
+                AnalogSignalLevel::Level0700_0000 => "0.700 : 0.000 : 0.700",
+            };
+            writeln!(f, "    Signal Level Standard: {level} V p-p")?;
+            if blank_to_black_setup {
+                writeln!(f, "    Blank-to-black setup/pedestal")?;
+            } else {
+                writeln!(f, "    Blank level equals black level")?;
+            }
+            if separate_sync {
+                writeln!(f, "    Separate sync supported")?;
+            }
+            if composite_sync {
+                writeln!(f, "    Composite sync (on HSync) supported")?;
+            }
+            if sync_on_green {
+                writeln!(f, "    Sync on green supported")?;
+            }
+            if serrated_vsync {
+                writeln!(f, "    Serration on VSync supported")?;
+            }
+        }
+    }
+    match (display.width, display.height) {
+        (0, 0) => writeln!(f, "    Image size is variable")?,
+        (width, 0) | (0, width) if version >= (1, 4) => {
+            writeln!(f, "    Aspect ratio: {:.2}", (width as f32 + 99.0) / 100.0)?
+        }
+        (width, height) => writeln!(f, "    Maximum image size: {width} cm x {height} cm")?,
+    }
+    match display.gamma {
+        Some(gamma) => writeln!(f, "    Gamma: {gamma:.2}")?,
+        None => writeln!(f, "    Gamma is defined in an extension block")?,
+    }
+    let features = &display.features;
+    if features.dpms_standby || features.dpms_suspend || features.dpms_active_off {
+        let levels = [
+            (features.dpms_standby, "Standby"),
+            (features.dpms_suspend, "Suspend"),
+            (features.dpms_active_off, "Off"),
+        ]
+        .into_iter()
+        .filter(|(supported, _)| *supported)
+        .map(|(_, level)| level)
+        .collect::<Vec<_>>();
+        writeln!(f, "    DPMS levels: {}", levels.join(" "))?;
+    }
+    match features.color {
+        ColorSupport::Encoding(encoding) => {
+            let formats = match encoding {
+                ColorEncoding::Rgb444 => "RGB 4:4:4",
+                ColorEncoding::Rgb444YCrCb444 => "RGB 4:4:4, YCrCb 4:4:4",
+                ColorEncoding::Rgb444YCrCb422 => "RGB 4:4:4, YCrCb 4:2:2",
+                ColorEncoding::Rgb444YCrCb444YCrCb422 => "RGB 4:4:4, YCrCb 4:4:4, YCrCb 4:2:2",
+            };
+            writeln!(f, "    Supported color formats: {formats}")?;
+        }
+        ColorSupport::DisplayType(color_type) => match color_type {
+            DisplayColorType::Monochrome => writeln!(f, "    Monochrome or grayscale display")?,
+            DisplayColorType::Rgb => writeln!(f, "    RGB color display")?,
+            DisplayColorType::NonRgb => writeln!(f, "    Non-RGB color display")?,
+            DisplayColorType::Undefined => writeln!(f, "    Undefined display color type")?,
+        },
+    }
+    if features.srgb_default {
+        writeln!(f, "    Default (sRGB) color space is primary color space")?;
+    }
+    if version >= (1, 4) {
+        if features.preferred_timing_native {
+            writeln!(f, "    First detailed timing includes the native pixel format and preferred refresh rate")?;
+        }
+        if features.continuous_frequency {
+            writeln!(f, "    Display is continuous frequency")?;
+        }
+    } else {
+        if features.preferred_timing_native {
+            writeln!(f, "    First detailed timing is the preferred timing")?;
+        }
+        if features.continuous_frequency {
+            writeln!(f, "    Supports GTF timings within operating range")?;
+        }
+    }
+
+    let chromaticity = &edid.chromaticity;
+    writeln!(f, "  Color Characteristics:")?;
+    for (name, xy) in [
+        ("Red  ", &chromaticity.red),
+        ("Green", &chromaticity.green),
+        ("Blue ", &chromaticity.blue),
+        ("White", &chromaticity.white),
+    ] {
+        writeln!(f, "    {name}: {:.4}, {:.4}", xy.x, xy.y)?;
+    }
+
+    let established = edid.established_timings.modes();
+    let manufacturer_timings = edid.established_timings.manufacturer_timings();
+    if established.is_empty() && manufacturer_timings == 0 {
+        writeln!(f, "  Established Timings I & II: none")?;
+    } else {
+        writeln!(f, "  Established Timings I & II:")?;
+        for mode in established {
+            write_video_mode(f, "    ", &mode)?;
+        }
+        if manufacturer_timings != 0 {
+            writeln!(
+                f,
+                "    Manufacturer-specified timings: 0x{manufacturer_timings:02x}"
+            )?;
+        }
+    }
+
+    if edid.standard_timings.is_empty() {
+        writeln!(f, "  Standard Timings: none")?;
+    } else {
+        writeln!(f, "  Standard Timings:")?;
+        for timing in edid.standard_timings.iter() {
+            writeln!(
+                f,
+                "    {:>5}x{:<5} {:>3} Hz {:>5}",
+                timing.horizontal_active_pixels,
+                timing.vertical_active_lines(),
+                timing.refresh_rate,
+                aspect_ratio(&timing.aspect_ratio)
+            )?;
+        }
+    }
+
+    writeln!(f, "  Detailed Timing Descriptors:")?;
+    for (n, descriptor) in edid.descriptors.iter().enumerate() {
+        write_descriptor(f, n, descriptor)?;
+    }
+    if edid.num_extr != 0 {
+        writeln!(f, "  Extension blocks: {}", edid.num_extr)?;
+    }
+    Ok(())
+}
+
+fn audio_format(format: &AudioFormat) -> String {
+    match format {
+        AudioFormat::Lpcm => "Linear PCM".to_string(),
+        AudioFormat::Ac3 => "AC-3".to_string(),
+        AudioFormat::Mpeg1 => "MPEG 1 (Layers 1 & 2)".to_string(),
+        AudioFormat::Mp3 => "MPEG 1 Layer 3 (MP3)".to_string(),
+        AudioFormat::Mpeg2 => "MPEG2 (multichannel)".to_string(),
+        AudioFormat::AacLc => "AAC LC".to_string(),
+        AudioFormat::Dts => "DTS".to_string(),
+        AudioFormat::Atrac => "ATRAC".to_string(),
+        AudioFormat::OneBitAudio => "One Bit Audio".to_string(),
+        AudioFormat::EnhancedAc3 => "Enhanced AC-3 (DD+)".to_string(),
+        AudioFormat::DtsHd => "DTS-HD".to_string(),
+        AudioFormat::Mat => "MAT (MLP)".to_string(),
+        AudioFormat::Dst => "DST".to_string(),
+        AudioFormat::WmaPro => "WMA Pro".to_string(),
+        AudioFormat::Extended(code) => format!("Extended Audio Format Code {code}"),
+        AudioFormat::Reserved(code) => format!("Reserved Audio Format Code {code}"),
+    }
+}
+
+fn speaker(speaker: &Speaker) -> &'static str {
+    match speaker {
+        Speaker::FrontLeftRight => "FL/FR - Front Left/Right",
+        Speaker::LowFrequencyEffects => "LFE1 - Low Frequency Effects 1",
+        Speaker::FrontCenter => "FC - Front Center",
+        Speaker::BackLeftRight => "BL/BR - Back Left/Right",
+        Speaker::BackCenter => "BC - Back Center",
+        Speaker::FrontLeftRightCenter => "FLc/FRc - Front Left/Right of Center",
+        Speaker::RearLeftRightCenter => "RLC/RRC - Rear Left/Right of Center",
+        Speaker::FrontLeftRightWide => "FLw/FRw - Front Left/Right Wide",
+        Speaker::TopFrontLeftRight => "TpFL/TpFR - Top Front Left/Right",
+        Speaker::TopCenter => "TpC - Top Center",
+        Speaker::TopFrontCenter => "TpFC - Top Front Center",
+        Speaker::LeftRightSurround => "LS/RS - Left/Right Surround",
+        Speaker::LowFrequencyEffects2 => "LFE2 - Low Frequency Effects 2",
+        Speaker::TopBackCenter => "TpBC - Top Back Center",
+        Speaker::SideLeftRight => "SiL/SiR - Side Left/Right",
+        Speaker::TopSideLeftRight => "TpSiL/TpSiR - Top Side Left/Right",
+    }
+}
+
+fn write_oui(f: &mut Formatter<'_>, oui: u32) -> fmt::Result {
+    let [_, a, b, c] = oui.to_be_bytes();
+    write!(f, "OUI {a:02X}-{b:02X}-{c:02X}")
+}
+
+/// recover the raw code of a luminance value encoded as `50 * 2^(code / 32)`
+fn luminance_code(luminance: f32) -> u8 {
+    (32.0 * (luminance / 50.0).log2()).round() as u8
+}
+
+/// recover the raw code of the min luminance, which is encoded relative to the max luminance
+fn min_luminance_code(luminance: f32, max_luminance: f32) -> u8 {
+    (255.0 * (luminance * 100.0 / max_luminance).sqrt()).round() as u8
+}
+
+fn write_cta861_block(f: &mut Formatter<'_>, cta: &Cta861Extension) -> fmt::Result {
+    writeln!(f, "  Revision: {}", cta.revision)?;
+    if cta.underscan {
+        writeln!(f, "  Underscans IT Video Formats by default")?;
+    }
+    if cta.basic_audio {
+        writeln!(f, "  Basic audio support")?;
+    }
+    if cta.ycbcr444 {
+        writeln!(f, "  Supports YCbCr 4:4:4")?;
+    }
+    if cta.ycbcr422 {
+        writeln!(f, "  Supports YCbCr 4:2:2")?;
+    }
+    writeln!(
+        f,
+        "  Native detailed modes: {}",
+        cta.native_detailed_timings
+    )?;
+    for block in cta.data_blocks.iter() {
+        match block {
+            CtaDataBlock::Video(descriptors) => {
+                writeln!(f, "  Video Data Block:")?;
+                for descriptor in descriptors {
+                    write!(f, "    VIC {:>3}", descriptor.vic)?;
+                    if descriptor.native {
+                        write!(f, " (native)")?;
+                    }
+                    writeln!(f)?;
+                }
+            }
+            CtaDataBlock::Audio(descriptors) => {
+                writeln!(f, "  Audio Data Block:")?;
+                for descriptor in descriptors {
+                    writeln!(f, "    {}:", audio_format(&descriptor.format))?;
+                    writeln!(f, "      Max channels: {}", descriptor.max_channels)?;
+                    let rates = descriptor
+                        .sample_rates()
+                        .into_iter()
+                        .rev()
+                        .map(|rate| (rate as f32 / 1000.0).to_string())
+                        .collect::<Vec<_>>();
+                    writeln!(f, "      Supported sample rates (kHz): {}", rates.join(" "))?;
+                    let depths = descriptor.bit_depths();
+                    if !depths.is_empty() {
+                        let depths = depths
+                            .into_iter()
+                            .rev()
+                            .map(|depth| depth.to_string())
+                            .collect::<Vec<_>>();
+                        writeln!(
+                            f,
+                            "      Supported sample sizes (bits): {}",
+                            depths.join(" ")
+                        )?;
+                    }
+                    if let Some(rate) = descriptor.max_bit_rate() {
+                        writeln!(f, "      Maximum bit rate: {rate} kb/s")?;
+                    }
+                }
+            }
+            CtaDataBlock::SpeakerAllocation(allocation) => {
+                writeln!(f, "  Speaker Allocation Data Block:")?;
+                for entry in allocation.speakers() {
+                    writeln!(f, "    {}", speaker(&entry))?;
+                }
+            }
+            CtaDataBlock::Hdmi(hdmi) => {
+                writeln!(f, "  Vendor-Specific Data Block (HDMI), OUI 00-0C-03:")?;
+                writeln!(f, "    Source physical address: {}", hdmi.physical_address)?;
+                if hdmi.supports_ai {
+                    writeln!(f, "    Supports_AI")?;
+                }
+                if hdmi.deep_color_48bit {
+                    writeln!(f, "    DC_48bit")?;
+                }
+                if hdmi.deep_color_36bit {
+                    writeln!(f, "    DC_36bit")?;
+                }
+                if hdmi.deep_color_30bit {
+                    writeln!(f, "    DC_30bit")?;
+                }
+                if hdmi.deep_color_ycbcr444 {
+                    writeln!(f, "    DC_Y444")?;
+                }
+                if hdmi.dvi_dual_link {
+                    writeln!(f, "    DVI_Dual")?;
+                }
+                if let Some(clock) = hdmi.max_tmds_clock {
+                    writeln!(f, "    Maximum TMDS clock: {clock} MHz")?;
+                }
+            }
+            CtaDataBlock::HdmiForum(hdmi) => {
+                writeln!(
+                    f,
+                    "  Vendor-Specific Data Block (HDMI Forum), OUI C4-5D-D8:"
+                )?;
+                writeln!(f, "    Version: {}", hdmi.version)?;
+                if let Some(rate) = hdmi.max_tmds_character_rate {
+                    writeln!(f, "    Maximum TMDS Character Rate: {rate} MHz")?;
+                }
+                if hdmi.scdc_present {
+                    writeln!(f, "    SCDC Present")?;
+                }
+                if hdmi.scdc_read_request {
+                    writeln!(f, "    SCDC Read Request Capable")?;
+                }
+                if hdmi.scrambling_below_340mcsc {
+                    writeln!(f, "    Supports scrambling for <= 340 Mcsc")?;
+                }
+                if hdmi.deep_color_420_48bit {
+                    writeln!(
+                        f,
+                        "    Supports 16-bits/component Deep Color 4:2:0 Pixel Encoding"
+                    )?;
+                }
+                if hdmi.deep_color_420_36bit {
+                    writeln!(
+                        f,
+                        "    Supports 12-bits/component Deep Color 4:2:0 Pixel Encoding"
+                    )?;
+                }
+                if hdmi.deep_color_420_30bit {
+                    writeln!(
+                        f,
+                        "    Supports 10-bits/component Deep Color 4:2:0 Pixel Encoding"
+                    )?;
+                }
+                if hdmi.max_frl_rate != 0 {
+                    writeln!(f, "    Max Fixed Rate Link: {}", hdmi.max_frl_rate)?;
+                }
+            }
+            CtaDataBlock::VendorSpecific { oui, payload } => {
+                write!(f, "  Vendor-Specific Data Block, ")?;
+                write_oui(f, *oui)?;
+                writeln!(f, ":")?;
+                write_hex(f, "    ", payload)?;
+            }
+            CtaDataBlock::Colorimetry(colorimetry) => {
+                writeln!(f, "  Colorimetry Data Block:")?;
+                for (supported, name) in [
+                    (colorimetry.xv_ycc_601, "xvYCC601"),
+                    (colorimetry.xv_ycc_709, "xvYCC709"),
+                    (colorimetry.s_ycc_601, "sYCC601"),
+                    (colorimetry.op_ycc_601, "opYCC601"),
+                    (colorimetry.op_rgb, "opRGB"),
+                    (colorimetry.bt2020_c_ycc, "BT2020cYCC"),
+                    (colorimetry.bt2020_ycc, "BT2020YCC"),
+                    (colorimetry.bt2020_rgb, "BT2020RGB"),
+                    (colorimetry.ictcp, "ICtCp"),
+                    (colorimetry.dci_p3, "DCI-P3"),
+                ] {
+                    if supported {
+                        writeln!(f, "    {name}")?;
+                    }
+                }
+            }
+            CtaDataBlock::HdrStaticMetadata(hdr) => {
+                writeln!(f, "  HDR Static Metadata Data Block:")?;
+                writeln!(f, "    Electro optical transfer functions:")?;
+                for (supported, name) in [
+                    (
+                        hdr.eotf_traditional_sdr,
+                        "Traditional gamma - SDR luminance range",
+                    ),
+                    (
+                        hdr.eotf_traditional_hdr,
+                        "Traditional gamma - HDR luminance range",
+                    ),
+                    (hdr.eotf_smpte_st2084, "SMPTE ST2084"),
+                    (hdr.eotf_hlg, "Hybrid Log-Gamma"),
+                ] {
+                    if supported {
+                        writeln!(f, "      {name}")?;
+                    }
+                }
+                writeln!(f, "    Supported static metadata descriptors:")?;
+                if hdr.static_metadata_type1 {
+                    writeln!(f, "      Static metadata type 1")?;
+                }
+                if let Some(luminance) = hdr.max_luminance {
+                    writeln!(
+                        f,
+                        "    Desired content max luminance: {} ({luminance:.3} cd/m^2)",
+                        luminance_code(luminance)
+                    )?;
+                }
+                if let Some(luminance) = hdr.max_frame_average_luminance {
+                    writeln!(
+                        f,
+                        "    Desired content max frame-average luminance: {} ({luminance:.3} cd/m^2)",
+                        luminance_code(luminance)
+                    )?;
+                }
+                if let (Some(luminance), Some(max)) = (hdr.min_luminance, hdr.max_luminance) {
+                    writeln!(
+                        f,
+                        "    Desired content min luminance: {} ({luminance:.3} cd/m^2)",
+                        min_luminance_code(luminance, max)
+                    )?;
+                }
+            }
+            CtaDataBlock::VideoFormatPreference(vics) => {
+                writeln!(f, "  Video Format Preference Data Block:")?;
+                for vic in vics {
+                    writeln!(f, "    VIC {vic:>3}")?;
+                }
+            }
+            CtaDataBlock::Extended { tag, payload } => {
+                writeln!(
+                    f,
+                    "  Unknown CTA-861 Data Block (extended tag 0x{tag:02x}):"
+                )?;
+                write_hex(f, "    ", payload)?;
+            }
+            CtaDataBlock::Unknown { tag, payload } => {
+                writeln!(f, "  Unknown CTA-861 Data Block (tag 0x{tag:02x}):")?;
+                write_hex(f, "    ", payload)?;
+            }
+        }
+    }
+    if !cta.detailed_timings.is_empty() {
+        writeln!(f, "  Detailed Timing Descriptors:")?;
+        for (n, timing) in cta.detailed_timings.iter().enumerate() {
+            write_detailed_timing(f, &format!("DTD {}", n + 1), timing)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_display_id_block(f: &mut Formatter<'_>, display_id: &DisplayId) -> fmt::Result {
+    writeln!(
+        f,
+        "  Version: {}.{}",
+        display_id.version, display_id.revision
+    )?;
+    writeln!(f, "  Extension Count: {}", display_id.extension_count)?;
+    for block in display_id.data_blocks.iter() {
+        match block {
+            DisplayIdDataBlock::ProductIdentification(product) => {
+                writeln!(f, "  Product Identification Data Block:")?;
+                match product.manufacturer {
+                    ManufacturerId::Pnp(vendor) => {
+                        writeln!(f, "    Vendor ID: {}", vendor.iter().collect::<String>())?
+                    }
+                    ManufacturerId::Oui(oui) => {
+                        write!(f, "    Vendor ID: ")?;
+                        write_oui(f, oui)?;
+                        writeln!(f)?;
+                    }
+                }
+                writeln!(f, "    Product Code: {}", product.product)?;
+                if product.serial != 0 {
+                    writeln!(f, "    Serial Number: {}", product.serial)?;
+                }
+                let year = product.year as u16 + 2000;
+                match product.week {
+                    0xff => writeln!(f, "    Model year: {year}")?,
+                    0 => writeln!(f, "    Made in: {year}")?,
+                    week => writeln!(f, "    Made in: week {week} of {year}")?,
+                }
+                if !product.product_name.is_empty() {
+                    writeln!(f, "    Product ID: {}", product.product_name)?;
+                }
+            }
+            DisplayIdDataBlock::TiledTopology(tile) => {
+                writeln!(f, "  Tiled Display Topology Data Block:")?;
+                if tile.single_enclosure {
+                    writeln!(f, "    Single physical display enclosure")?;
+                }
+                writeln!(
+                    f,
+                    "    Number of horizontal tiles: {} Number of vertical tiles: {}",
+                    tile.horizontal_tiles, tile.vertical_tiles
+                )?;
+                writeln!(
+                    f,
+                    "    Tile location: {}, {}",
+                    tile.horizontal_location, tile.vertical_location
+                )?;
+                writeln!(
+                    f,
+                    "    Tile resolution: {}x{}",
+                    tile.tile_width, tile.tile_height
+                )?;
+            }
+            DisplayIdDataBlock::TypeITimings(timings)
+            | DisplayIdDataBlock::TypeVIITimings(timings) => {
+                let timing_type = match block {
+                    DisplayIdDataBlock::TypeITimings(_) => 1,
+                    _ => 7,
+                };
+                writeln!(
+                    f,
+                    "  Video Timing Modes Type {timing_type} - Detailed Timings Data Block:"
+                )?;
+                for timing in timings {
+                    write!(
+                        f,
+                        "    DTD: {:>5}x{:<5} {:>10.6} Hz {:>5} {:>11.6} MHz",
+                        timing.horizontal_active_pixels,
+                        timing.vertical_active_lines,
+                        timing.refresh_rate(),
+                        ratio(
+                            timing.horizontal_active_pixels as u32,
+                            timing.vertical_active_lines as u32
+                        ),
+                        timing.pixel_clock as f64 / 1000.0
+                    )?;
+                    if timing.preferred {
+                        write!(f, " (preferred)")?;
+                    }
+                    writeln!(f)?;
+                }
+            }
+            DisplayIdDataBlock::AdaptiveSync(ranges) => {
+                writeln!(f, "  Adaptive-Sync Data Block:")?;
+                for range in ranges {
+                    writeln!(
+                        f,
+                        "    Refresh rate range: {}-{} Hz{}",
+                        range.min_refresh_rate,
+                        range.max_refresh_rate,
+                        if range.native { " (native)" } else { "" }
+                    )?;
+                }
+            }
+            DisplayIdDataBlock::Unknown {
+                tag,
+                revision,
+                payload,
+            } => {
+                writeln!(
+                    f,
+                    "  Unknown DisplayID Data Block (tag 0x{tag:02x}, revision {revision}):"
+                )?;
+                write_hex(f, "    ", payload)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Display for EdidReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "edid-decode (hex):")?;
+        writeln!(f)?;
+        for block in 0..self.data.len() / EDID_BLOCK_LENGTH {
+            write_hex(f, "", self.block(block))?;
+            writeln!(f)?;
+        }
+        writeln!(f, "{SEPARATOR}")?;
+
+        for block in 0..self.data.len() / EDID_BLOCK_LENGTH {
+            writeln!(f)?;
+            writeln!(f, "Block {block}, {}:", self.block_name(block))?;
+            let data = self.block(block);
+            if block == 0 {
+                write_base_block(f, &self.edid)?;
+            } else if data[0] == CTA861_EXTENSION_TAG {
+                if let Ok(cta) = parse_cta861_extension(data) {
+                    write_cta861_block(f, &cta)?;
+                }
+            } else if data[0] == DISPLAY_ID_EXTENSION_TAG {
+                if let Ok(display_id) = parse_display_id_extension(data) {
+                    write_display_id_block(f, &display_id)?;
+                }
+            }
+            let (checksum, expected) = checksum(data);
+            if checksum == expected {
+                writeln!(f, "Checksum: 0x{checksum:02x}")?;
+            } else {
+                writeln!(f, "Checksum: 0x{checksum:02x} (should be 0x{expected:02x})")?;
+            }
+            writeln!(f)?;
+            writeln!(f, "{SEPARATOR}")?;
+        }
+
+        let issues = self.issues();
+        for (severity, title) in [
+            (Severity::Warning, "Warnings"),
+            (Severity::Failure, "Failures"),
+        ] {
+            let issues = issues
+                .iter()
+                .filter(|issue| issue.severity == severity)
+                .collect::<Vec<_>>();
+            if issues.is_empty() {
+                continue;
+            }
+            writeln!(f)?;
+            writeln!(f, "{title}:")?;
+            let mut current_block = None;
+            for issue in issues {
+                if current_block != Some(issue.block) {
+                    writeln!(f)?;
+                    writeln!(
+                        f,
+                        "Block {}, {}:",
+                        issue.block,
+                        self.block_name(issue.block)
+                    )?;
+                    current_block = Some(issue.block);
+                }
+                writeln!(f, "  {}", issue.message)?;
+            }
+        }
+        writeln!(f)?;
+        if issues
+            .iter()
+            .any(|issue| issue.severity == Severity::Failure)
+        {
+            writeln!(f, "EDID conformity: FAIL")
+        } else {
+            writeln!(f, "EDID conformity: PASS")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EdidReport;
+    use crate::ddc::{
+        display_id::test::DISPLAY_ID_EXTENSION,
+        edid::{cta861::test::CTA861_BLOCK, test::EDID_BASE_BLOCK},
+    };
+
+    #[test]
+    fn report_base_block() {
+        let report = EdidReport::new(&EDID_BASE_BLOCK).unwrap();
+        assert!(report.conformant());
+        let output = report.to_string();
+        assert!(output.contains("Block 0, Base EDID:\n  EDID Structure Version & Revision: 1.4\n"));
+        assert!(output.contains("    Manufacturer: DEL\n"));
+        assert!(output.contains("    Display Product Name: 'DELL U2415'\n"));
+        assert!(output.contains(
+            "      Monitor ranges (GTF): 56-76 Hz V, 30-81 kHz H, max dotclock 170 MHz\n"
+        ));
+        assert!(output.ends_with("EDID conformity: PASS\n"));
+
+        let mut corrupt = EDID_BASE_BLOCK;
+        corrupt[127] = corrupt[127].wrapping_add(1);
+        let report = EdidReport::new(&corrupt).unwrap();
+        assert!(!report.conformant());
+        assert_eq!(report.failures().len(), 1);
+        assert!(report.to_string().ends_with("EDID conformity: FAIL\n"));
+    }
+
+    fn edid_with_extensions(count: u8) -> Vec<u8> {
+        let mut data = EDID_BASE_BLOCK.to_vec();
+        data[127] = data[127].wrapping_add(data[126]).wrapping_sub(count);
+        data[126] = count;
+        data.extend_from_slice(&CTA861_BLOCK);
+        data.extend_from_slice(&DISPLAY_ID_EXTENSION);
+        data
+    }
+
+    #[test]
+    fn report_extensions() {
+        let report = EdidReport::new(&edid_with_extensions(2)).unwrap();
+        assert!(report.conformant());
+        assert!(report.warnings().is_empty());
+        let output = report.to_string();
+        assert!(output.contains(concat!(
+            "  Detailed Timing Descriptors:\n",
+            "    DTD 1:  1920x1200   59.950171 Hz   8:5    74.038 kHz  154.000000 MHz (518 mm x 324 mm)\n",
+            "                 Hfront   48 Hsync  32 Hback   80 Hpol N\n",
+            "                 Vfront    3 Vsync   6 Vback   26 Vpol N\n",
+        )));
+        assert!(output.contains(concat!(
+            "Block 1, CTA-861 Extension Block:\n",
+            "  Revision: 3\n",
+            "  Underscans IT Video Formats by default\n",
+            "  Basic audio support\n",
+            "  Supports YCbCr 4:4:4\n",
+            "  Supports YCbCr 4:2:2\n",
+            "  Native detailed modes: 1\n",
+            "  Video Data Block:\n",
+            "    VIC  16 (native)\n",
+            "    VIC   4\n",
+            "    VIC  31\n",
+            "    VIC  97\n",
+            "  Audio Data Block:\n",
+            "    Linear PCM:\n",
+            "      Max channels: 2\n",
+            "      Supported sample rates (kHz): 48 44.1 32\n",
+            "      Supported sample sizes (bits): 24 20 16\n",
+            "  Speaker Allocation Data Block:\n",
+            "    FL/FR - Front Left/Right\n",
+            "  Vendor-Specific Data Block (HDMI), OUI 00-0C-03:\n",
+            "    Source physical address: 1.0.0.0\n",
+        )));
+        assert!(output.contains(concat!(
+            "  HDR Static Metadata Data Block:\n",
+            "    Electro optical transfer functions:\n",
+            "      Traditional gamma - SDR luminance range\n",
+            "      SMPTE ST2084\n",
+            "    Supported static metadata descriptors:\n",
+            "      Static metadata type 1\n",
+            "    Desired content max luminance: 120 (672.717 cd/m^2)\n",
+            "    Desired content max frame-average luminance: 92 (366.802 cd/m^2)\n",
+            "    Desired content min luminance: 0 (0.000 cd/m^2)\n",
+        )));
+        assert!(output.contains(concat!(
+            "  Detailed Timing Descriptors:\n",
+            "    DTD 1:  1920x1080   60.000000 Hz  16:9    67.500 kHz  148.500000 MHz (708 mm x 398 mm)\n",
+            "                 Hfront   88 Hsync  44 Hback  148 Hpol P\n",
+            "                 Vfront    4 Vsync   5 Vback   36 Vpol P\n",
+            "Checksum: 0x3a\n",
+        )));
+        assert!(output.contains(concat!(
+            "Block 2, DisplayID Extension Block:\n",
+            "  Version: 2.0\n",
+            "  Extension Count: 0\n",
+            "  Product Identification Data Block:\n",
+            "    Vendor ID: OUI 00-10-FA\n",
+            "    Product Code: 4660\n",
+            "    Serial Number: 1\n",
+            "    Made in: week 16 of 2028\n",
+            "    Product ID: Tile\n",
+            "  Tiled Display Topology Data Block:\n",
+            "    Single physical display enclosure\n",
+            "    Number of horizontal tiles: 2 Number of vertical tiles: 1\n",
+            "    Tile location: 1, 0\n",
+            "    Tile resolution: 2560x2880\n",
+        )));
+        assert!(output.ends_with("----------------\n\nEDID conformity: PASS\n"));
+    }
+
+    #[test]
+    fn report_warnings_and_failures() {
+        let mut data = edid_with_extensions(3);
+        data[127] = data[127].wrapping_add(1);
+        let report = EdidReport::new(&data).unwrap();
+        assert!(!report.conformant());
+        assert_eq!(
+            report.warnings(),
+            ["Block 0, Base EDID: Only 2 of 3 extension blocks are present."]
+        );
+        assert_eq!(
+            report.failures(),
+            ["Block 0, Base EDID: Invalid checksum 0xc3 (should be 0xc2)."]
+        );
+        assert!(report.to_string().ends_with(concat!(
+            "----------------\n",
+            "\n",
+            "Warnings:\n",
+            "\n",
+            "Block 0, Base EDID:\n",
+            "  Only 2 of 3 extension blocks are present.\n",
+            "\n",
+            "Failures:\n",
+            "\n",
+            "Block 0, Base EDID:\n",
+            "  Invalid checksum 0xc3 (should be 0xc2).\n",
+            "\n",
+            "EDID conformity: FAIL\n",
+        )));
+    }
+}
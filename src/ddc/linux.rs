@@ -1,42 +1,68 @@
 use i2cdev::{
     core::I2CTransfer,
-    linux::{I2CMessage, LinuxI2CBus},
+    linux::{I2CMessage, LinuxI2CBus, LinuxI2CMessage},
 };
-use std::{ffi::OsStr, fs::File, io::Read, path::Path, time::Duration};
+use std::{
+    ffi::{CString, OsStr},
+    fs::File,
+    io::{self, Read},
+    os::unix::ffi::OsStrExt,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use thiserror::Error;
 use udev::Device;
 
 use super::{
-    eddc::{EDDC_SEGMENT_POINTER_ADDR, EDID_ADDRESS},
-    edid::{parse_edid, Edid},
+    display_id::{parse_display_id, DisplayId},
+    eddc::{DISPLAY_ID_ADDRESS, EDDC_SEGMENT_POINTER_ADDR, EDID_ADDRESS},
+    edid::{parse_edid, Edid, EDID_BLOCK_LENGTH},
     Ddc, DdcCiError, DdcCommunicationBase, DdcDevice, DeriveDdcCiDevice,
 };
 
 const RECEIVE_EDID_RETRIES: u8 = 3;
 
-/// this function only reads the first 128 of edid, this
-/// can be reasonably assumed to be present on all display devices
+/// read a single edid block, blocks past the first 256 bytes are addressed using the eddc segment
+/// pointer
+fn receive_edid_block(
+    i2c_bus: &mut LinuxI2CBus,
+    block: u8,
+) -> Result<[u8; EDID_BLOCK_LENGTH], anyhow::Error> {
+    let segment = [block / 2];
+    let offset = [(block % 2) * EDID_BLOCK_LENGTH as u8];
+    let mut data = [0; EDID_BLOCK_LENGTH];
+    if segment[0] == 0 {
+        i2c_bus
+            .transfer(&mut [
+                LinuxI2CMessage::write(&offset).with_address(EDID_ADDRESS.into()),
+                LinuxI2CMessage::read(&mut data).with_address(EDID_ADDRESS.into()),
+            ])
+            .map_err(anyhow::Error::new)?;
+    } else {
+        // the segment pointer is reset after each stop condition, so all messages need to be send
+        // in a single transfer
+        i2c_bus
+            .transfer(&mut [
+                LinuxI2CMessage::write(&segment).with_address(EDDC_SEGMENT_POINTER_ADDR.into()),
+                LinuxI2CMessage::write(&offset).with_address(EDID_ADDRESS.into()),
+                LinuxI2CMessage::read(&mut data).with_address(EDID_ADDRESS.into()),
+            ])
+            .map_err(anyhow::Error::new)?;
+    }
+    Ok(data)
+}
+
+/// read the edid base block followed by all extension blocks announced in the base block
 pub fn receive_edid(i2c_bus: &mut LinuxI2CBus) -> Result<Edid, anyhow::Error> {
     // reset eddc segment pointer. May fail if display does not implement eddc for specific input
     // some displays behave differently depending on the input source.
-    let _ = i2c_bus
-        .transfer(&mut [i2cdev::linux::LinuxI2CMessage::write(&[0x0])
-            .with_address(EDDC_SEGMENT_POINTER_ADDR.into())]);
+    let _ = i2c_bus.transfer(&mut [i2cdev::linux::LinuxI2CMessage::write(&[0x0])
+        .with_address(EDDC_SEGMENT_POINTER_ADDR.into())]);
 
     let mut receive_try = RECEIVE_EDID_RETRIES;
     loop {
-        //initiate edid reading
-        i2c_bus
-            .transfer(&mut [i2cdev::linux::LinuxI2CMessage::write(&[0x0])
-                .with_address(EDID_ADDRESS.into())])
-            .map_err(|err| anyhow::Error::new(err))?;
-        //read first 128 bytes of edid
-        let mut data: [u8; 128] = [0; 128];
-        let _ = i2c_bus
-            .transfer(&mut [
-                i2cdev::linux::LinuxI2CMessage::read(&mut data).with_address(EDID_ADDRESS.into()),
-            ])
-            .map_err(|err| anyhow::Error::new(err))?;
-        let x = parse_edid(&data).map_err(|err| anyhow::Error::new(err));
+        let x = receive_edid_blocks(i2c_bus)
+            .and_then(|data| parse_edid(&data).map_err(anyhow::Error::new));
         if receive_try == 0 {
             return x;
         } else {
@@ -48,6 +74,49 @@ pub fn receive_edid(i2c_bus: &mut LinuxI2CBus) -> Result<Edid, anyhow::Error> {
     }
 }
 
+fn receive_edid_blocks(i2c_bus: &mut LinuxI2CBus) -> Result<Vec<u8>, anyhow::Error> {
+    let mut data = receive_edid_block(i2c_bus, 0)?.to_vec();
+    // number of extension blocks is stored in the second to last byte of the base block
+    let num_extensions = data[EDID_BLOCK_LENGTH - 2];
+    for block in 1..=num_extensions {
+        data.extend_from_slice(&receive_edid_block(i2c_bus, block)?);
+    }
+    Ok(data)
+}
+
+fn receive_display_id_block(
+    i2c_bus: &mut LinuxI2CBus,
+    offset: u8,
+) -> Result<[u8; EDID_BLOCK_LENGTH], anyhow::Error> {
+    let mut data = [0; EDID_BLOCK_LENGTH];
+    i2c_bus
+        .transfer(&mut [
+            LinuxI2CMessage::write(&[offset]).with_address(DISPLAY_ID_ADDRESS.into()),
+            LinuxI2CMessage::read(&mut data).with_address(DISPLAY_ID_ADDRESS.into()),
+        ])
+        .map_err(anyhow::Error::new)?;
+    Ok(data)
+}
+
+/// read a DisplayID structure, the address is limited to 8 bit offsets so at most 256 bytes are
+/// available
+pub fn receive_display_id(i2c_bus: &mut LinuxI2CBus) -> Result<DisplayId, anyhow::Error> {
+    read_display_id_blocks(|offset| receive_display_id_block(i2c_bus, offset))
+}
+
+/// read the first block and the second one if the structure does not fit into the first block
+fn read_display_id_blocks(
+    mut read_block: impl FnMut(u8) -> Result<[u8; EDID_BLOCK_LENGTH], anyhow::Error>,
+) -> Result<DisplayId, anyhow::Error> {
+    let mut data = read_block(0)?.to_vec();
+    // section header: version, payload length, product type, extension count
+    let section_length = data[1] as usize + 5;
+    if section_length > EDID_BLOCK_LENGTH || data[3] > 0 {
+        data.extend_from_slice(&read_block(EDID_BLOCK_LENGTH as u8)?);
+    }
+    parse_display_id(&data).map_err(anyhow::Error::new)
+}
+
 // filter phantom devices, devices connected via docking stations may appear as two seperate
 // i2c devices with only one working (workarount copied from ddcutil)
 fn is_phantom_ddc_device(dev: &Device) -> bool {
@@ -60,11 +129,12 @@ fn is_phantom_ddc_device(dev: &Device) -> bool {
     }
 
     let device_parent = find_device_parent.unwrap();
-    let device_path = Path::new("/sys").join(format!(".{}", device_parent.devpath().to_string_lossy()));
+    let device_path =
+        Path::new("/sys").join(format!(".{}", device_parent.devpath().to_string_lossy()));
     if device_path.exists() {
         let enabled_path = device_path.join("enabled");
         let status_path = device_path.join("status");
-        if enabled_path.exists()
+        enabled_path.exists()
             && File::open(enabled_path)
                 .and_then(|mut f| {
                     let mut content = String::new();
@@ -80,11 +150,6 @@ fn is_phantom_ddc_device(dev: &Device) -> bool {
                     Ok(content.trim().to_string())
                 })
                 .is_ok_and(|content| content == "disconnected")
-        {
-            true
-        } else {
-            false
-        }
     } else {
         // it can not be a valid device if it is not found in the
         // system tree
@@ -121,62 +186,108 @@ fn device_is_display(dev: &udev::Device) -> bool {
     })
 }
 
-fn find_parent_drm_device(i2c_dev: &udev::Device) -> Option<Device> {
-    if let Some(i2c_parent) = i2c_dev.parent() {
-        // assuming this is a graphics device, other devices should have been filtered beforehand
-        if let Some(graphics_device) = i2c_parent.parent() {
-            if graphics_device
-                .subsystem()
-                .is_some_and(|subsystem| subsystem == "drm")
-            {
-                // display device with type drm are easy because it is already correctly mapped
-                return Some(graphics_device);
-            } else if graphics_device
-                .property_value("ID_PCI_CLASS_FROM_DATABASE")
-                .is_some_and(|class| class == "Display controller")
-            {
-                //display i2c bus but not of type drm are harder because no we need to loop over all devices of class drm and
-                //find one with a matiching edid data to the one read via the i2c channel
-
-                let mut i2c =
-                    LinuxI2CBus::new(format!("/dev/i2c-{}", i2c_dev.sysnum().unwrap())).unwrap();
-                match receive_edid(&mut i2c) {
-                    Ok(i2c_edid) => {
-                        let mut drm_enum = udev::Enumerator::new().unwrap();
-                        drm_enum.match_subsystem("drm").ok();
-                        let devices = drm_enum.scan_devices().unwrap();
-
-                        for (drm_device, edid_data) in devices.filter_map(|dev| {
-                            // only consider drm devices
-                            let edid_path = dev.syspath().join("edid");
-                            let mut edid_data = [0 as u8; 128];
-                            if edid_path.exists()
-                                && File::open(&edid_path)
-                                    .unwrap()
-                                    .read(&mut edid_data)
-                                    .is_ok_and(|size| size > 0)
-                            {
-                                Some((dev, edid_data))
-                            } else {
-                                None
-                            }
-                        }) {
-                            if parse_edid(&edid_data).is_ok_and(|drm_edid| drm_edid == i2c_edid) {
-                                return Some(drm_device);
-                            }
-                        }
-                    }
-                    Err(_err) => { /*println!("{err:#?}");*/ }
-                };
-            }
+fn open_i2c_bus(sysnum: usize) -> Result<LinuxI2CBus, io::Error> {
+    LinuxI2CBus::new(format!("/dev/i2c-{sysnum}")).map_err(io::Error::from)
+}
+
+/// read the edid of a display controller that is not part of the drm subsystem and find the drm
+/// connector reporting the same edid
+fn find_drm_device_by_edid(i2c_dev: &udev::Device) -> Result<Device, SkipReason> {
+    let mut i2c =
+        open_i2c_bus(i2c_dev.sysnum().unwrap_or_default()).map_err(|err| match err.kind() {
+            io::ErrorKind::PermissionDenied => SkipReason::PermissionDenied,
+            _ => SkipReason::Io(anyhow::Error::new(err)),
+        })?;
+    let i2c_edid = receive_edid(&mut i2c).map_err(SkipReason::EdidUnreadable)?;
+    let mut drm_enum = udev::Enumerator::new().map_err(|_| SkipReason::NoDrmMatch)?;
+    drm_enum.match_subsystem("drm").ok();
+    let devices = drm_enum
+        .scan_devices()
+        .map_err(|_| SkipReason::NoDrmMatch)?;
+
+    for (drm_device, edid_data) in devices.filter_map(|dev| {
+        // only consider drm devices
+        let edid_path = dev.syspath().join("edid");
+        let mut edid_data = Vec::new();
+        if edid_path.exists()
+            && File::open(&edid_path)
+                .and_then(|mut f| f.read_to_end(&mut edid_data))
+                .is_ok_and(|size| size > 0)
+        {
+            Some((dev, edid_data))
+        } else {
+            None
         }
+    }) {
+        if parse_edid(&edid_data).is_ok_and(|drm_edid| drm_edid == i2c_edid) {
+            return Ok(drm_device);
+        }
+    }
+    Err(SkipReason::NoDrmMatch)
+}
+
+fn find_parent_drm_device(i2c_dev: &udev::Device) -> Result<Device, SkipReason> {
+    // assuming this is a graphics device, other devices should have been filtered beforehand
+    let graphics_device = i2c_dev
+        .parent()
+        .and_then(|i2c_parent| i2c_parent.parent())
+        .ok_or(SkipReason::NoDrmMatch)?;
+    if graphics_device
+        .subsystem()
+        .is_some_and(|subsystem| subsystem == "drm")
+    {
+        // display device with type drm are easy because it is already correctly mapped
+        Ok(graphics_device)
+    } else if graphics_device
+        .property_value("ID_PCI_CLASS_FROM_DATABASE")
+        .is_some_and(|class| class == "Display controller")
+    {
+        //display i2c bus but not of type drm are harder because no we need to loop over all devices of class drm and
+        //find one with a matiching edid data to the one read via the i2c channel
+        find_drm_device_by_edid(i2c_dev)
+    } else {
+        Err(SkipReason::NoDrmMatch)
     }
-    None
+}
+
+/// Reason an i2c bus is not used as a display during enumeration
+#[derive(Debug, Error)]
+pub enum SkipReason {
+    /// inactive duplicate of a bus, created by some docking stations
+    #[error("phantom device")]
+    PhantomDevice,
+    /// the adapter name is missing or belongs to a bus unrelated to displays
+    #[error("ignored by adapter name")]
+    IgnoredName,
+    #[error("not a display controller")]
+    NotDisplayController,
+    #[error("no matching drm connector")]
+    NoDrmMatch,
+    #[error("edid unreadable: {0}")]
+    EdidUnreadable(anyhow::Error),
+    #[error("permission denied")]
+    PermissionDenied,
+    /// reading from the bus or the drm connector failed
+    #[error("i/o error: {0}")]
+    Io(anyhow::Error),
+}
+
+/// Candidate i2c bus that has been skipped during enumeration
+#[derive(Debug, Error)]
+#[error("{} ({}): {reason}", bus.display(), adapter.as_deref().unwrap_or("unnamed"))]
+pub struct SkippedBus {
+    /// device node of the bus, e.g. "/dev/i2c-4"
+    pub bus: PathBuf,
+    /// name of the i2c adapter
+    pub adapter: Option<String>,
+    pub reason: SkipReason,
 }
 
 pub struct LinuxDdcDevice {
     i2c_sysnum: usize,
     drm_device: udev::Device,
+    /// bus handle that is opened on first use and kept for all following transfers
+    i2c_bus: Option<LinuxI2CBus>,
 }
 
 impl LinuxDdcDevice {
@@ -184,15 +295,62 @@ impl LinuxDdcDevice {
         Self {
             i2c_sysnum,
             drm_device,
+            i2c_bus: None,
         }
     }
 
+    /// sysfs path of the drm connector the display is attached to
+    pub fn drm_syspath(&self) -> &Path {
+        self.drm_device.syspath()
+    }
+
+    /// the drm connector reports a display, connectors without a `status` attribute are assumed
+    /// to be connected
+    pub fn connected(&self) -> bool {
+        self.drm_device
+            .attribute_value("status")
+            .is_none_or(|status| status != "disconnected")
+    }
+
+    /// number of the i2c bus, e.g. 4 for "/dev/i2c-4"
+    pub fn i2c_sysnum(&self) -> usize {
+        self.i2c_sysnum
+    }
+
     fn device_sysnum(&self) -> usize {
         self.i2c_sysnum
     }
 
-    fn open_i2c_bus(&self) -> LinuxI2CBus {
-        LinuxI2CBus::new(format!("/dev/i2c-{}", self.device_sysnum())).unwrap()
+    /// get the open bus handle or reconnect if no handle is open
+    fn i2c_bus(&mut self) -> Result<&mut LinuxI2CBus, DdcCiError> {
+        let i2c_bus = match self.i2c_bus.take() {
+            Some(i2c_bus) => i2c_bus,
+            None => open_i2c_bus(self.device_sysnum())
+                .map_err(|err| DdcCiError::OpenError(anyhow::Error::new(err)))?,
+        };
+        Ok(self.i2c_bus.insert(i2c_bus))
+    }
+
+    /// run a transfer on the bus, the handle is closed if the i2c adapter is gone so the next
+    /// transfer reopens the bus once the adapter has been recreated
+    fn transfer<'a>(
+        &mut self,
+        msgs: &'a mut [LinuxI2CMessage<'a>],
+        error: fn(anyhow::Error) -> DdcCiError,
+    ) -> Result<(), DdcCiError> {
+        let result = self.i2c_bus()?.transfer(msgs).map_err(io::Error::from);
+        if matches!(&result, Err(err) if err.raw_os_error() == Some(libc::ENODEV)) {
+            self.i2c_bus = None;
+        }
+        result.map(|_| ()).map_err(|err| {
+            // errno values used by i2c adapters for transfers that are not acknowledged, see the
+            // i2c fault codes of the kernel documentation
+            if matches!(err.raw_os_error(), Some(libc::ENXIO | libc::EREMOTEIO)) {
+                DdcCiError::NoAcknowledge(anyhow::Error::new(err))
+            } else {
+                error(anyhow::Error::new(err))
+            }
+        })
     }
 }
 
@@ -203,10 +361,7 @@ impl DdcCommunicationBase for LinuxDdcDevice {
 
     fn transmit(&mut self, addr: u8, data: &[u8]) -> Result<(), super::DdcCiError> {
         let msg = i2cdev::linux::LinuxI2CMessage::write(data).with_address(addr.into());
-        self.open_i2c_bus()
-            .transfer(&mut [msg])
-            .map_err(|err| DdcCiError::TransmitError(anyhow::Error::new(err)))?;
-        Ok(())
+        self.transfer(&mut [msg], DdcCiError::TransmitError)
     }
 
     fn receive(
@@ -216,9 +371,7 @@ impl DdcCommunicationBase for LinuxDdcDevice {
         let mut data = [0; super::I2C_DDC_RECV_BUFFER_SIZE];
         data[0] = addr << 1 | 0x1;
         let msg = i2cdev::linux::LinuxI2CMessage::read(&mut data[1..]).with_address(addr.into());
-        self.open_i2c_bus()
-            .transfer(&mut [msg])
-            .map_err(|err| DdcCiError::ReceiveError(anyhow::Error::new(err)))?;
+        self.transfer(&mut [msg], DdcCiError::ReceiveError)?;
         Ok(data)
     }
 }
@@ -236,65 +389,182 @@ impl DdcDevice for LinuxDdcDevice {
     }
 
     fn read_edid(&mut self) -> Result<super::edid::Edid, super::DdcError> {
+        Ok(parse_edid(&self.read_edid_data()?)?)
+    }
+
+    fn read_edid_data(&mut self) -> Result<Vec<u8>, super::DdcError> {
         let edid_path = self.drm_device.syspath().join("edid");
         let mut edid_data = File::open(edid_path)?;
-        let mut data = [0 as u8; 128];
-        let _size = edid_data.read(&mut data)?;
-        Ok(parse_edid(&data)?)
+        let mut data = Vec::new();
+        let _size = edid_data.read_to_end(&mut data)?;
+        Ok(data)
+    }
+
+    /// connector name prefixed with the pci path of the gpu, e.g. "pci-0000:03:00.0-DP-1", the
+    /// card number is not used as it depends on the order the gpus are probed in
+    fn connector_path(&self) -> Option<String> {
+        let card = self.drm_device.parent()?;
+        let gpu_path = match card.property_value("ID_PATH") {
+            Some(path) => path.to_string_lossy().to_string(),
+            None => format!("pci-{}", card.parent()?.sysname().to_string_lossy()),
+        };
+        Some(format!("{gpu_path}-{}", self.name()))
+    }
+
+    fn read_display_id(&mut self) -> Result<DisplayId, super::DdcError> {
+        // displays without a dedicated DisplayID structure may embed it into the edid instead
+        let display_id = receive_display_id(self.i2c_bus()?);
+        display_id.or_else(|err| {
+            log::debug!("reading DisplayID of {} failed: {err:#}", self.name());
+            self.read_edid()?
+                .display_id()?
+                .ok_or(super::DdcError::DisplayIdUnavailable)
+        })
     }
 }
 
 impl DeriveDdcCiDevice for LinuxDdcDevice {}
 impl Ddc for LinuxDdcDevice {}
 
-struct LinuxDrmI2C {
-    drm_device: udev::Device,
-    i2c_device: udev::Device,
-}
-
 pub struct LinuxDdcDeviceEnumerator {
-    inner_iter: Box<dyn Iterator<Item = LinuxDrmI2C>>,
+    inner_iter: Box<dyn Iterator<Item = LinuxDdcDevice>>,
 }
 
 impl LinuxDdcDeviceEnumerator {
+    /// enumerate all usable display devices, buses that can not be used are skipped silently
+    ///
+    /// buses are only checked once the iterator reaches them and are not opened before they are
+    /// used
     pub fn iter() -> Self {
-        let mut i2c_enum = udev::Enumerator::new().unwrap();
-        i2c_enum.match_subsystem("i2c-dev").ok();
+        let i2c_devices = scan_i2c_devices().unwrap_or_default();
+        Self {
+            inner_iter: Box::new(
+                i2c_devices
+                    .into_iter()
+                    .filter_map(|i2c_device| probe_i2c_device(&i2c_device).ok()),
+            ),
+        }
+    }
+
+    /// check every i2c bus and report the reason for skipping buses that can not be used
+    pub fn detailed() -> Result<Vec<Result<LinuxDdcDevice, SkippedBus>>, io::Error> {
+        Self::detailed_with(Ok)
+    }
 
-        let devices = i2c_enum.scan_devices().unwrap();
-        let filtered: Vec<LinuxDrmI2C> = devices
+    /// check every i2c bus and apply `open` to the usable ones, its errors are reported for the
+    /// bus of the device
+    pub(crate) fn detailed_with<T>(
+        open: impl Fn(LinuxDdcDevice) -> Result<T, SkipReason>,
+    ) -> Result<Vec<Result<T, SkippedBus>>, io::Error> {
+        Ok(scan_i2c_devices()?
             .into_iter()
-            .filter(|dev| {
-                dev.attribute_value("name")
-                    .is_some_and(|name| !ignore_device_by_name(name))
-            })
-            .filter(|dev| device_is_display(dev))
-            .filter(|dev| !is_phantom_ddc_device(dev))
-            .filter_map(|i2c_device| {
-                if let Some(drm_device) = find_parent_drm_device(&i2c_device) {
-                    Some(LinuxDrmI2C {
-                        drm_device,
-                        i2c_device,
+            .map(|i2c_device| {
+                let adapter = i2c_device
+                    .attribute_value("name")
+                    .map(|name| name.to_string_lossy().to_string());
+                let bus = i2c_device
+                    .devnode()
+                    .unwrap_or(i2c_device.syspath())
+                    .to_path_buf();
+                probe_i2c_device(&i2c_device)
+                    .and_then(|device| {
+                        // the bus is opened lazily, only check that it could be opened
+                        if has_access(&bus) {
+                            open(device)
+                        } else {
+                            Err(SkipReason::PermissionDenied)
+                        }
+                    })
+                    .map_err(|reason| SkippedBus {
+                        bus,
+                        adapter,
+                        reason,
                     })
-                } else {
-                    None
-                }
             })
-            .collect();
-        Self {
-            inner_iter: Box::new(filtered.into_iter()),
-        }
+            .collect())
+    }
+}
+
+fn scan_i2c_devices() -> Result<Vec<udev::Device>, io::Error> {
+    let mut i2c_enum = udev::Enumerator::new()?;
+    i2c_enum.match_subsystem("i2c-dev")?;
+    Ok(i2c_enum.scan_devices()?.collect())
+}
+
+/// check if the device node may be opened for reading and writing without opening it
+fn has_access(devnode: &Path) -> bool {
+    let Ok(path) = CString::new(devnode.as_os_str().as_bytes()) else {
+        return false;
+    };
+    // SAFETY: path is a valid nul terminated string for the duration of the call
+    unsafe { libc::access(path.as_ptr(), libc::R_OK | libc::W_OK) == 0 }
+}
+
+/// apply the enumeration filters to a single i2c device
+fn probe_i2c_device(i2c_device: &udev::Device) -> Result<LinuxDdcDevice, SkipReason> {
+    if i2c_device
+        .attribute_value("name")
+        .is_none_or(ignore_device_by_name)
+    {
+        return Err(SkipReason::IgnoredName);
     }
+    let sysnum = i2c_device
+        .sysnum()
+        .filter(|_| device_is_display(i2c_device))
+        .ok_or(SkipReason::NotDisplayController)?;
+    if is_phantom_ddc_device(i2c_device) {
+        return Err(SkipReason::PhantomDevice);
+    }
+    let drm_device = find_parent_drm_device(i2c_device)?;
+    Ok(LinuxDdcDevice::new(sysnum, drm_device))
 }
 
 impl Iterator for LinuxDdcDeviceEnumerator {
     type Item = LinuxDdcDevice;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner_iter.next().and_then(|dev| {
-            dev.i2c_device
-                .sysnum()
-                .map(|id| LinuxDdcDevice::new(id, dev.drm_device))
+        self.inner_iter.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::read_display_id_blocks;
+    use crate::ddc::edid::EDID_BLOCK_LENGTH;
+
+    /// DisplayID 1.3 section containing a single data block with the given payload length
+    fn display_id_section(extension_count: u8, block_length: u8) -> Vec<u8> {
+        let mut section = vec![0x13, block_length + 3, 0x00, extension_count];
+        section.extend_from_slice(&[0x7f, 0x00, block_length]);
+        section.resize(section.len() + block_length as usize, 0xaa);
+        section.push(0u8.wrapping_sub(section.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))));
+        section
+    }
+
+    /// read the data in blocks of 128 bytes padded with zeros, returns the number of blocks read
+    fn read_blocks(data: &[u8]) -> (usize, usize) {
+        let mut reads = Vec::new();
+        let display_id = read_display_id_blocks(|offset| {
+            reads.push(offset);
+            let mut block = [0; EDID_BLOCK_LENGTH];
+            let start = (offset as usize).min(data.len());
+            let end = (start + EDID_BLOCK_LENGTH).min(data.len());
+            block[..end - start].copy_from_slice(&data[start..end]);
+            Ok(block)
         })
+        .unwrap();
+        (reads.len(), display_id.data_blocks.len())
+    }
+
+    #[test]
+    fn display_id_block_count() {
+        // section fits into the first block
+        assert_eq!(read_blocks(&display_id_section(0, 120)), (1, 1));
+        // section exceeds the first block
+        assert_eq!(read_blocks(&display_id_section(0, 150)), (2, 1));
+        // extension sections follow the first section
+        let mut data = display_id_section(1, 60);
+        data.extend(display_id_section(0, 60));
+        assert_eq!(read_blocks(&data), (2, 2));
     }
 }
@@ -0,0 +1,75 @@
+//! Scripted DDC/CI device for testing the protocol implementation without a display
+
+use std::collections::VecDeque;
+
+use super::{
+    ci::DdcCiMessage,
+    edid::{Edid, EdidBuilder},
+    Ddc, DdcCiError, DdcCommunicationBase, DdcDevice, DdcError, DeriveDdcCiDevice,
+    I2C_DDC_RECV_BUFFER_SIZE,
+};
+
+/// Device answering with queued replies, all transmitted messages are recorded
+#[derive(Debug, Default)]
+pub(crate) struct MockDdcDevice {
+    /// transmit buffers of all messages sent to the device
+    pub transmitted: Vec<Vec<u8>>,
+    transmit_errors: VecDeque<DdcCiError>,
+    replies: VecDeque<Result<[u8; I2C_DDC_RECV_BUFFER_SIZE], DdcCiError>>,
+}
+
+impl MockDdcDevice {
+    /// queue a reply message
+    pub fn reply(mut self, message: DdcCiMessage) -> Self {
+        let mut data = [0; I2C_DDC_RECV_BUFFER_SIZE];
+        let buffer = message.transmit_buffer();
+        data[0] = message.addr() << 1 | 0x1;
+        data[1..=buffer.len()].copy_from_slice(&buffer);
+        self.replies.push_back(Ok(data));
+        self
+    }
+
+    /// let the next receive fail
+    pub fn receive_error(mut self, error: DdcCiError) -> Self {
+        self.replies.push_back(Err(error));
+        self
+    }
+
+    /// let the next transmission fail
+    pub fn transmit_error(mut self, error: DdcCiError) -> Self {
+        self.transmit_errors.push_back(error);
+        self
+    }
+}
+
+impl DdcCommunicationBase for MockDdcDevice {
+    fn transmit(&mut self, _addr: u8, data: &[u8]) -> Result<(), DdcCiError> {
+        if let Some(error) = self.transmit_errors.pop_front() {
+            return Err(error);
+        }
+        self.transmitted.push(data.to_vec());
+        Ok(())
+    }
+
+    fn receive(&mut self, _addr: u8) -> Result<[u8; I2C_DDC_RECV_BUFFER_SIZE], DdcCiError> {
+        self.replies
+            .pop_front()
+            .unwrap_or_else(|| Err(DdcCiError::ReceiveError(anyhow::anyhow!("no reply queued"))))
+    }
+
+    fn delay(&self, _delay_ms: u64) {}
+}
+
+impl DeriveDdcCiDevice for MockDdcDevice {}
+
+impl DdcDevice for MockDdcDevice {
+    fn name(&self) -> String {
+        "mock".to_string()
+    }
+
+    fn read_edid(&mut self) -> Result<Edid, DdcError> {
+        Ok(EdidBuilder::new().build().unwrap())
+    }
+}
+
+impl Ddc for MockDdcDevice {}
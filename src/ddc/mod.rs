@@ -11,21 +11,30 @@ pub mod edid;
 /// eddc definitons
 pub mod eddc;
 
+/// display id data parsing
+pub mod display_id;
+
 #[cfg(target_os = "linux")]
 pub mod linux;
 #[cfg(target_os = "macos")]
 mod mac_os;
+#[cfg(test)]
+pub(crate) mod mock;
 #[cfg(target_os = "windows")]
 mod windows;
 use thiserror::Error;
 
 use self::{
-    ci::{parse_feature_reply, DdcCiMessage, DdcCiProtocolError, DdcOpcode, ResultCode},
+    ci::{
+        parse_feature_reply, parse_timing_report, DdcCiMessage, DdcCiProtocolError, DdcOpcode,
+        FeatureReplyMessage, RawVcpValue, ResultCode, TimingReport,
+    },
+    display_id::{DisplayId, DisplayIdParseError},
     edid::{Edid, EdidParseError},
 };
 use crate::mccs::{
     capabilities::{parse_capabilities, Capabilities},
-    features::VcpValue,
+    features::{ConfirmRestore, RestoreDefaults, VcpFeatureCode, VcpValue},
 };
 
 pub const I2C_DDC_RECV_BUFFER_SIZE: usize = 64;
@@ -36,20 +45,37 @@ pub enum DdcError {
     ReadDataError(#[from] std::io::Error),
     #[error("Error Parsing Edid Data {0}")]
     EdidParseError(#[from] EdidParseError),
+    #[error("Error Parsing DisplayID Data {0}")]
+    DisplayIdParseError(#[from] DisplayIdParseError),
+    #[error("Display does not provide DisplayID data")]
+    DisplayIdUnavailable,
     #[error("Internal Displays do not support DDC/CI")]
     InternalDisplay,
     #[error("Communication Error")]
     CommunicationError(#[from] DdcCiError),
     #[error("Unsupported Vcp Feature")]
     UnsupportedVcpFeature,
+    #[error("Display is asleep and does not respond to DDC/CI requests")]
+    DisplayAsleep,
+    #[error("Vcp Feature {0:?} is protected and can not be set directly")]
+    ProtectedVcpFeature(VcpFeatureCode),
+    #[error("Vcp Feature {code:?} reported the reserved value {value:#x}")]
+    UnexpectedVcpValue { code: VcpFeatureCode, value: u32 },
 }
 
 #[derive(Debug, Error)]
 pub enum DdcCiError {
+    #[error("Error opening DDC bus: {0}")]
+    OpenError(anyhow::Error),
     #[error("Error sending DDC data: {0}")]
     TransmitError(anyhow::Error),
     #[error("Error receiving DDC data: {0}")]
     ReceiveError(anyhow::Error),
+    /// the display did not acknowledge the transfer, e.g. because it is in a power saving mode
+    #[error("DDC transfer not acknowledged: {0}")]
+    NoAcknowledge(anyhow::Error),
+    #[error("DDC/CI display only replied with null messages")]
+    NullResponse,
     #[error("DDC/CI Protocol Error! {0}")]
     ProtocolError(#[from] DdcCiProtocolError),
     #[error("DDC/CI unexpected ReplyCode")]
@@ -78,24 +104,35 @@ pub trait DdcCiDevice {
     /// Sets a VCP feature to the specified value.
     fn set_vcp_feature<V: VcpValue>(&mut self, vcp_value: V) -> Result<(), DdcError>;
 
+    /// Gets the current value of a VCP feature selected by its raw feature code.
+    fn get_vcp_raw(&mut self, code: u8) -> Result<RawVcpValue, DdcError>;
+
+    /// Sets a VCP feature selected by its raw feature code to the specified value.
+    fn set_vcp_raw(&mut self, code: u8, value: u16) -> Result<(), DdcError>;
+
+    /// Restore settings of the device to its factory defaults.
+    fn restore_defaults(
+        &mut self,
+        kind: RestoreDefaults,
+        confirmation: ConfirmRestore,
+    ) -> Result<(), DdcError>;
+
     /// Instruct the device to save its current settings.
     fn save_current_settings(&mut self) -> Result<(), DdcError>;
 
-    // Retrieves a timing report from the device.
-    //fn get_timing_report(&mut self) -> Result<TimingMessage, DdcError> {
-    //    todo!()
-    //}
-
-    // Read a table value from the device.
-    //fn table_read(&mut self, code: VcpFeatureCode) -> Result<Vec<u8>, DdcError>;
-
-    // Write a table value to the device.
-    //fn table_write(
-    //&mut self,
-    //code: VcpFeatureCode,
-    //offset: u16,
-    //value: &[u8],
-    //) -> Result<(), DdcError>;
+    /// Retrieves a timing report from the device.
+    fn get_timing_report(&mut self) -> Result<TimingReport, DdcError>;
+
+    /// Read a table value from the device.
+    fn table_read(&mut self, code: VcpFeatureCode) -> Result<Vec<u8>, DdcError>;
+
+    /// Write a table value to the device starting at the given offset.
+    fn table_write(
+        &mut self,
+        code: VcpFeatureCode,
+        offset: u16,
+        value: &[u8],
+    ) -> Result<(), DdcError>;
 }
 
 impl<X> DdcCiDevice for X
@@ -116,7 +153,7 @@ where
         // get first capabilities reply
         let mut capabilities_reply =
             DdcCiMessage::parse_buffer(&self.receive(capabilities_request.addr())?)
-                .map_err(|err| DdcCiError::ProtocolError(err))?;
+                .map_err(DdcCiError::ProtocolError)?;
 
         // keep requesting more capabilities data until it has been read compleatly (indicated by a 0 length capabilities reply)
         let mut capabilities_buffer = Vec::new();
@@ -132,7 +169,7 @@ where
             self.delay(50);
             capabilities_reply =
                 DdcCiMessage::parse_buffer(&self.receive(capabilities_request.addr())?)
-                    .map_err(|err| DdcCiError::ProtocolError(err))?;
+                    .map_err(DdcCiError::ProtocolError)?;
         }
 
         let cap_str = String::from_utf8(capabilities_buffer).unwrap();
@@ -141,47 +178,126 @@ where
     }
 
     fn get_vcp_feature<V: VcpValue>(&mut self) -> Result<V, DdcError> {
-        let get_vcp_request =
-            DdcCiMessage::from_opcode(ci::DdcOpcode::VcpRequest).set_vcp_feature(V::vcp_feature());
-        self.transmit(get_vcp_request.addr(), &get_vcp_request.transmit_buffer())?;
-        self.delay(40);
-        let mut get_vcp_reply = DdcCiMessage::parse_buffer(&self.receive(get_vcp_request.addr())?)
-            .map_err(|err| DdcCiError::ProtocolError(err))?;
-
-        let mut retry = 3;
-        // if null message we need to retry after a timout
-        while retry > 0 && get_vcp_reply == DdcCiMessage::NullResponse() {
-            self.transmit(get_vcp_request.addr(), &get_vcp_request.transmit_buffer())?;
-            self.delay(40);
-            get_vcp_reply = DdcCiMessage::parse_buffer(&self.receive(get_vcp_request.addr())?)
-                .map_err(|err| DdcCiError::ProtocolError(err))?;
-            retry -= 1;
-        }
-        if get_vcp_reply
+        let vcp_resp = request_vcp_feature(self, V::vcp_feature())?;
+        Ok(vcp_resp.vcp_data().into())
+    }
+
+    fn set_vcp_feature<V: VcpValue>(&mut self, vcp_value: V) -> Result<(), DdcError> {
+        write_vcp_feature(self, V::vcp_feature(), vcp_value.vh(), vcp_value.vl())
+    }
+
+    fn get_vcp_raw(&mut self, code: u8) -> Result<RawVcpValue, DdcError> {
+        let vcp_resp = request_vcp_feature(self, code.into())?;
+        Ok((&vcp_resp).into())
+    }
+
+    fn set_vcp_raw(&mut self, code: u8, value: u16) -> Result<(), DdcError> {
+        let [vh, vl] = value.to_be_bytes();
+        write_vcp_feature(self, code.into(), vh, vl)
+    }
+
+    fn restore_defaults(
+        &mut self,
+        kind: RestoreDefaults,
+        _confirmation: ConfirmRestore,
+    ) -> Result<(), DdcError> {
+        // restore operations are triggered by writing any non zero value
+        transmit_set_vcp(self, kind.vcp_feature(), 0x00, 0x01)
+    }
+
+    fn get_timing_report(&mut self) -> Result<TimingReport, DdcError> {
+        let timing_request = DdcCiMessage::from_opcode(ci::DdcOpcode::TimingRequest);
+        let timing_reply = request_reply(self, &timing_request, 40)?;
+        if timing_reply
             .get_opcode()
-            .is_some_and(|opcode| *opcode == DdcOpcode::VcpReply)
+            .is_some_and(|opcode| *opcode == DdcOpcode::TimingReply)
         {
-            let (_, vcp_resp) = parse_feature_reply(get_vcp_reply.get_data()).map_err(|err| {
-                println!("{get_vcp_reply:#x?}");
-                DdcCiError::ProtocolError(err.into())
-            })?;
-            if *vcp_resp.result_code() == ResultCode::UnsupportedCode {
-                Err(DdcError::UnsupportedVcpFeature)
-            } else {
-                Ok(vcp_resp.vcp_data().into())
-            }
+            let (_, report) = parse_timing_report(timing_reply.get_data())
+                .map_err(|err| DdcCiError::ProtocolError(err.into()))?;
+            Ok(report)
         } else {
             Err(DdcCiError::UnexpectedReplyCode.into())
         }
     }
 
-    fn set_vcp_feature<V: VcpValue>(&mut self, vcp_value: V) -> Result<(), DdcError> {
-        let set_vcp_request = DdcCiMessage::from_opcode(ci::DdcOpcode::SetVcp)
-            .set_vcp_feature(V::vcp_feature())
-            .set_data(&[vcp_value.vh(), vcp_value.vl()])
-            .map_err(|err| DdcCiError::ProtocolError(err))?;
-        self.transmit(set_vcp_request.addr(), &set_vcp_request.transmit_buffer())?;
-        self.delay(50);
+    fn table_read(&mut self, code: VcpFeatureCode) -> Result<Vec<u8>, DdcError> {
+        // keep requesting table fragments until the table has been read compleatly (indicated by a 0 length table reply)
+        let mut table_buffer = Vec::new();
+        let mut offset = 0;
+        loop {
+            let table_request = DdcCiMessage::from_opcode(ci::DdcOpcode::TableReadRequest)
+                .set_vcp_feature(code)
+                .set_offset(offset);
+            let table_reply = request_reply(self, &table_request, 50)?;
+            if !table_reply
+                .get_opcode()
+                .is_some_and(|opcode| *opcode == DdcOpcode::TableReadReply)
+            {
+                return Err(DdcCiError::UnexpectedReplyCode.into());
+            }
+            if table_reply.get_data_len() == 0 {
+                return Ok(table_buffer);
+            }
+            // a display answering with a different fragment would corrupt the table or never
+            // finish reading it
+            match table_reply.get_offset() {
+                Some(received) if received == offset => {}
+                Some(received) => {
+                    return Err(
+                        DdcCiError::ProtocolError(DdcCiProtocolError::UnexpectedOffset {
+                            expected: offset,
+                            received,
+                        })
+                        .into(),
+                    )
+                }
+                None => {
+                    return Err(
+                        DdcCiError::ProtocolError(DdcCiProtocolError::InvalidMessageFormat).into(),
+                    )
+                }
+            }
+            table_buffer.extend_from_slice(table_reply.get_data());
+            // next read should happen from offest + received data length
+            offset = offset
+                .checked_add(table_reply.get_data_len().into())
+                .ok_or(DdcCiError::ProtocolError(
+                    DdcCiProtocolError::OffsetOutOfRange,
+                ))?;
+        }
+    }
+
+    fn table_write(
+        &mut self,
+        code: VcpFeatureCode,
+        offset: u16,
+        value: &[u8],
+    ) -> Result<(), DdcError> {
+        if code.is_restore_operation() {
+            return Err(DdcError::ProtectedVcpFeature(code));
+        }
+        // the last byte has to be addressable, this is checked upfront so tables are never
+        // written partially
+        u16::try_from(value.len().saturating_sub(1))
+            .ok()
+            .and_then(|last| offset.checked_add(last))
+            .ok_or(DdcCiError::ProtocolError(
+                DdcCiProtocolError::OffsetOutOfRange,
+            ))?;
+        // tables larger than a single data fragment have to be written in multiple parts
+        for (n, fragment) in value.chunks(ci::DDC_MAX_DATA_FRAGMENT_LENGTH).enumerate() {
+            let fragment_offset = offset + (n * ci::DDC_MAX_DATA_FRAGMENT_LENGTH) as u16;
+            let table_write_request = DdcCiMessage::from_opcode(ci::DdcOpcode::TableWrite)
+                .set_vcp_feature(code)
+                .set_offset(fragment_offset)
+                .set_data(fragment)
+                .map_err(DdcCiError::ProtocolError)?;
+            self.transmit(
+                table_write_request.addr(),
+                &table_write_request.transmit_buffer(),
+            )?;
+            self.delay(50);
+        }
         Ok(())
     }
 
@@ -192,11 +308,368 @@ where
     }
 }
 
+/// transmit a request and receive its reply, null replies signal a busy display and the request
+/// is repeated up to three times
+fn request_reply<X>(
+    device: &mut X,
+    request: &DdcCiMessage,
+    delay_ms: u64,
+) -> Result<DdcCiMessage, DdcError>
+where
+    X: DeriveDdcCiDevice,
+{
+    for _ in 0..4 {
+        device.transmit(request.addr(), &request.transmit_buffer())?;
+        device.delay(delay_ms);
+        let reply = DdcCiMessage::parse_buffer(&device.receive(request.addr())?)
+            .map_err(DdcCiError::ProtocolError)?;
+        if reply != DdcCiMessage::NullResponse() {
+            return Ok(reply);
+        }
+    }
+    Err(DdcCiError::NullResponse.into())
+}
+
+/// request the current value of a vcp feature and return the parsed reply
+fn request_vcp_feature<X>(
+    device: &mut X,
+    code: VcpFeatureCode,
+) -> Result<FeatureReplyMessage, DdcError>
+where
+    X: DeriveDdcCiDevice,
+{
+    let get_vcp_request =
+        DdcCiMessage::from_opcode(ci::DdcOpcode::VcpRequest).set_vcp_feature(code);
+    let get_vcp_reply = request_reply(device, &get_vcp_request, 40)?;
+    if get_vcp_reply
+        .get_opcode()
+        .is_some_and(|opcode| *opcode == DdcOpcode::VcpReply)
+    {
+        let (_, vcp_resp) = parse_feature_reply(get_vcp_reply.get_data()).map_err(|err| {
+            log::debug!("invalid vcp feature reply {get_vcp_reply:#x?}");
+            DdcCiError::ProtocolError(err.into())
+        })?;
+        if *vcp_resp.result_code() == ResultCode::UnsupportedCode {
+            return Err(DdcError::UnsupportedVcpFeature);
+        }
+        // a reply to a previous request may still be pending on the bus
+        let (expected, received) = (u8::from(code), u8::from(vcp_resp.vcp_feature()));
+        if expected != received {
+            return Err(
+                DdcCiError::ProtocolError(DdcCiProtocolError::UnexpectedVcpFeature {
+                    expected,
+                    received,
+                })
+                .into(),
+            );
+        }
+        Ok(vcp_resp)
+    } else {
+        Err(DdcCiError::UnexpectedReplyCode.into())
+    }
+}
+
+/// set a vcp feature to the value given by its high and low byte
+fn write_vcp_feature<X>(
+    device: &mut X,
+    code: VcpFeatureCode,
+    vh: u8,
+    vl: u8,
+) -> Result<(), DdcError>
+where
+    X: DeriveDdcCiDevice,
+{
+    // restore operations have to be confirmed explicitly using `restore_defaults`
+    if code.is_restore_operation() {
+        return Err(DdcError::ProtectedVcpFeature(code));
+    }
+    transmit_set_vcp(device, code, vh, vl)
+}
+
+fn transmit_set_vcp<X>(device: &mut X, code: VcpFeatureCode, vh: u8, vl: u8) -> Result<(), DdcError>
+where
+    X: DeriveDdcCiDevice,
+{
+    let set_vcp_request = DdcCiMessage::from_opcode(ci::DdcOpcode::SetVcp)
+        .set_vcp_feature(code)
+        .set_data(&[vh, vl])
+        .map_err(DdcCiError::ProtocolError)?;
+    device.transmit(set_vcp_request.addr(), &set_vcp_request.transmit_buffer())?;
+    device.delay(50);
+    Ok(())
+}
+
 pub trait DdcDevice {
     fn name(&self) -> String;
 
     /// Read Edid Data from Ddc Device
     fn read_edid(&mut self) -> Result<Edid, DdcError>;
+
+    /// Read the raw edid data as provided by the display, by default the parsed edid is encoded
+    /// again which drops data the parser does not keep
+    fn read_edid_data(&mut self) -> Result<Vec<u8>, DdcError> {
+        Ok(self.read_edid()?.to_bytes())
+    }
+
+    /// Read DisplayID Data from Ddc Device, by default only DisplayID data embedded in the edid
+    /// extension blocks is available
+    fn read_display_id(&mut self) -> Result<DisplayId, DdcError> {
+        self.read_edid()?
+            .display_id()?
+            .ok_or(DdcError::DisplayIdUnavailable)
+    }
+
+    /// Stable path of the connector the display is attached to, used to tell identical displays
+    /// without serial numbers apart
+    fn connector_path(&self) -> Option<String> {
+        None
+    }
 }
 
 pub trait Ddc: DdcDevice + DdcCiDevice {}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        ci::{DdcCiMessage, DdcCiProtocolError, DdcOpcode, VcpType, DDC_MAX_DATA_FRAGMENT_LENGTH},
+        mock::MockDdcDevice,
+        DdcCiDevice, DdcCiError, DdcError,
+    };
+    use crate::mccs::features::{ConfirmRestore, RestoreDefaults, VcpFeatureCode, VcpValue};
+
+    const LUT: u8 = 0x73;
+
+    fn table_read_request(offset: u16) -> Vec<u8> {
+        DdcCiMessage::from_opcode(DdcOpcode::TableReadRequest)
+            .set_vcp_feature(LUT.into())
+            .set_offset(offset)
+            .transmit_buffer()
+    }
+
+    fn table_read_reply(offset: u16, data: &[u8]) -> DdcCiMessage {
+        DdcCiMessage::from_opcode(DdcOpcode::TableReadReply)
+            .set_offset(offset)
+            .set_data(data)
+            .unwrap()
+    }
+
+    fn vcp_reply(result_code: u8, code: u8, max: u16, value: u16) -> DdcCiMessage {
+        let [mh, ml] = max.to_be_bytes();
+        let [vh, vl] = value.to_be_bytes();
+        DdcCiMessage::from_opcode(DdcOpcode::VcpReply)
+            .set_data(&[result_code, code, 0x00, mh, ml, vh, vl])
+            .unwrap()
+    }
+
+    fn table_write_request(offset: u16, data: &[u8]) -> Vec<u8> {
+        DdcCiMessage::from_opcode(DdcOpcode::TableWrite)
+            .set_vcp_feature(LUT.into())
+            .set_offset(offset)
+            .set_data(data)
+            .unwrap()
+            .transmit_buffer()
+    }
+
+    #[test]
+    fn table_read_fragments() {
+        let table: Vec<u8> = (0..40).collect();
+        let mut device = MockDdcDevice::default()
+            .reply(table_read_reply(0, &table[..32]))
+            .reply(table_read_reply(32, &table[32..]))
+            .reply(table_read_reply(40, &[]));
+        assert_eq!(device.table_read(LUT.into()).unwrap(), table);
+        assert_eq!(
+            device.transmitted,
+            vec![
+                table_read_request(0),
+                table_read_request(32),
+                table_read_request(40)
+            ]
+        );
+
+        let mut device = MockDdcDevice::default()
+            .reply(table_read_reply(0, &table[..32]))
+            .reply(table_read_reply(0, &table[..32]));
+        assert!(matches!(
+            device.table_read(LUT.into()),
+            Err(DdcError::CommunicationError(DdcCiError::ProtocolError(
+                DdcCiProtocolError::UnexpectedOffset {
+                    expected: 32,
+                    received: 0
+                }
+            )))
+        ));
+    }
+
+    #[test]
+    fn table_read_busy() {
+        let table: Vec<u8> = (0..40).collect();
+        // a busy display answers with a null message, the fragment is requested again
+        let mut device = MockDdcDevice::default()
+            .reply(table_read_reply(0, &table[..32]))
+            .reply(DdcCiMessage::NullResponse())
+            .reply(table_read_reply(32, &table[32..]))
+            .reply(table_read_reply(40, &[]));
+        assert_eq!(device.table_read(LUT.into()).unwrap(), table);
+        assert_eq!(
+            device.transmitted,
+            vec![
+                table_read_request(0),
+                table_read_request(32),
+                table_read_request(32),
+                table_read_request(40)
+            ]
+        );
+
+        let mut device = MockDdcDevice::default().reply(table_read_reply(0, &table[..32]));
+        for _ in 0..4 {
+            device = device.reply(DdcCiMessage::NullResponse());
+        }
+        assert!(matches!(
+            device.table_read(LUT.into()),
+            Err(DdcError::CommunicationError(DdcCiError::NullResponse))
+        ));
+    }
+
+    #[test]
+    fn table_read_offset_overflow() {
+        let fragment = [0xaa; DDC_MAX_DATA_FRAGMENT_LENGTH];
+        let mut device = MockDdcDevice::default();
+        for offset in (0..=u16::MAX).step_by(DDC_MAX_DATA_FRAGMENT_LENGTH) {
+            device = device.reply(table_read_reply(offset, &fragment));
+        }
+        assert!(matches!(
+            device.table_read(LUT.into()),
+            Err(DdcError::CommunicationError(DdcCiError::ProtocolError(
+                DdcCiProtocolError::OffsetOutOfRange
+            )))
+        ));
+    }
+
+    #[test]
+    fn table_write_fragments() {
+        let table: Vec<u8> = (0..40).collect();
+        let mut device = MockDdcDevice::default();
+        device.table_write(LUT.into(), 0x10, &table).unwrap();
+        assert_eq!(
+            device.transmitted,
+            vec![
+                table_write_request(0x10, &table[..32]),
+                table_write_request(0x30, &table[32..])
+            ]
+        );
+
+        // the last fragment ends at the last addressable offset
+        let mut device = MockDdcDevice::default();
+        device
+            .table_write(LUT.into(), 0xffe0, &table[..32])
+            .unwrap();
+        assert_eq!(device.transmitted.len(), 1);
+
+        let mut device = MockDdcDevice::default();
+        assert!(matches!(
+            device.table_write(LUT.into(), 0xffe0, &table[..33]),
+            Err(DdcError::CommunicationError(DdcCiError::ProtocolError(
+                DdcCiProtocolError::OffsetOutOfRange
+            )))
+        ));
+        assert!(device.transmitted.is_empty());
+    }
+
+    #[test]
+    fn get_vcp_raw_reply() {
+        let mut device = MockDdcDevice::default()
+            .reply(DdcCiMessage::NullResponse())
+            .reply(vcp_reply(0x00, 0x10, 100, 42));
+        let value = device.get_vcp_raw(0x10).unwrap();
+        assert_eq!(value.vcp_type, VcpType::SetParameter);
+        assert_eq!((value.max, value.val), (100, 42));
+        // null responses are retried
+        assert_eq!(device.transmitted.len(), 2);
+
+        let mut device = MockDdcDevice::default().reply(vcp_reply(0x01, 0x10, 0, 0));
+        assert!(matches!(
+            device.get_vcp_raw(0x10),
+            Err(DdcError::UnsupportedVcpFeature)
+        ));
+
+        let mut device = MockDdcDevice::default().reply(vcp_reply(0x00, 0x12, 100, 42));
+        assert!(matches!(
+            device.get_vcp_raw(0x10),
+            Err(DdcError::CommunicationError(DdcCiError::ProtocolError(
+                DdcCiProtocolError::UnexpectedVcpFeature {
+                    expected: 0x10,
+                    received: 0x12
+                }
+            )))
+        ));
+    }
+
+    /// value of a restore operation, which the generic setters have to reject
+    #[derive(Clone, Copy)]
+    struct RestoreColor(u32);
+
+    impl From<u32> for RestoreColor {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<RestoreColor> for u32 {
+        fn from(value: RestoreColor) -> Self {
+            value.0
+        }
+    }
+
+    impl VcpValue for RestoreColor {
+        fn vcp_feature() -> VcpFeatureCode {
+            VcpFeatureCode::RestoreFactoryColor
+        }
+    }
+
+    fn set_vcp_request(code: u8, value: u16) -> Vec<u8> {
+        DdcCiMessage::from_opcode(DdcOpcode::SetVcp)
+            .set_vcp_feature(code.into())
+            .set_data(&value.to_be_bytes())
+            .unwrap()
+            .transmit_buffer()
+    }
+
+    #[test]
+    fn restore_operations_protected() {
+        let mut device = MockDdcDevice::default();
+        assert!(matches!(
+            device.set_vcp_raw(0x04, 1),
+            Err(DdcError::ProtectedVcpFeature(
+                VcpFeatureCode::RestoreFactoryDefaults
+            ))
+        ));
+        assert!(matches!(
+            device.set_vcp_feature(RestoreColor(1)),
+            Err(DdcError::ProtectedVcpFeature(
+                VcpFeatureCode::RestoreFactoryColor
+            ))
+        ));
+        assert!(matches!(
+            device.table_write(VcpFeatureCode::RestoreFactoryGeometry, 0, &[0x01]),
+            Err(DdcError::ProtectedVcpFeature(
+                VcpFeatureCode::RestoreFactoryGeometry
+            ))
+        ));
+        assert!(device.transmitted.is_empty());
+
+        for (kind, code) in [
+            (RestoreDefaults::Factory, 0x04),
+            (RestoreDefaults::LuminanceContrast, 0x05),
+            (RestoreDefaults::Geometry, 0x06),
+            (RestoreDefaults::Color, 0x08),
+            (RestoreDefaults::TvDefaults, 0x0a),
+        ] {
+            let mut device = MockDdcDevice::default();
+            device
+                .restore_defaults(kind, ConfirmRestore::confirmed())
+                .unwrap();
+            assert_eq!(device.transmitted, vec![set_vcp_request(code, 0x01)]);
+        }
+    }
+}
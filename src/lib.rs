@@ -4,17 +4,36 @@
 
 pub mod ddc;
 pub mod mccs;
+#[cfg(target_os = "linux")]
+mod watcher;
 
-use ddc::{edid::Edid, Ddc, DdcError};
+use ddc::{
+    edid::{parse_edid, Edid},
+    Ddc, DdcCiError, DdcDevice, DdcError,
+};
 use mccs::{
     capabilities::Capabilities,
-    features::{ContrastValue, InputSource, LuminanceValue, OsdLanguages},
+    features::{
+        AudioMute, AudioProcessorMode, AudioSpeakerVolumeValue, ColorPreset,
+        ColorTemperatureIncrementValue, ColorTemperatureRequestValue, ConfirmRestore,
+        ContinuousVcpValue, ContrastValue, DisplayControllerTypeValue, DisplayFirmwareLevelValue,
+        DisplayUsageTimeValue, InputSource, LuminanceValue, OsdLanguages, PowerMode,
+        RestoreDefaults, VcpCapability, VcpFeatureCode, VcpVersionValue, VideoBlackLevelBlueValue,
+        VideoBlackLevelGreenValue, VideoBlackLevelRedValue, VideoGainBlueValue,
+        VideoGainGreenValue, VideoGainRedValue, COLOR_TEMPERATURE_REQUEST_BASE_KELVIN,
+    },
+    Version,
 };
 use std::{fmt::Display, io};
 use thiserror::Error;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+use crate::ddc::linux::{LinuxDdcDevice, LinuxDdcDeviceEnumerator, SkipReason, SkippedBus};
 #[cfg(target_os = "linux")]
-use crate::ddc::linux::{LinuxDdcDevice, LinuxDdcDeviceEnumerator};
+pub use watcher::{MonitorEvent, MonitorWatcher};
 
 /// The error type for high level DDC/CI monitor operations.
 #[derive(Debug, Error)]
@@ -39,8 +58,12 @@ pub enum DisplayError {
 //#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[derive(Clone, Debug)]
 pub struct MonitorInfo {
+    id: MonitorId,
     edid: Edid,
+    /// edid data as read from the display
+    edid_data: Vec<u8>,
     mccs_features: Option<Capabilities>,
+    firmware: Option<MonitorFirmwareInfo>,
 }
 
 impl MonitorInfo {
@@ -52,9 +75,179 @@ impl MonitorInfo {
         self.edid.header.serial
     }
 
+    /// vendor PNP id and registered company name of the display manufacturer
+    pub fn vendor(&self) -> MonitorVendor {
+        MonitorVendor {
+            id: self.edid.header.vendor.iter().collect(),
+            name: self.edid.header.manufacturer_name(),
+        }
+    }
+
+    /// stable identity of the display
+    pub fn id(&self) -> MonitorId {
+        self.id
+    }
+
+    /// serial number string from the edid display descriptors
+    pub fn serial_number(&self) -> Option<&str> {
+        self.edid.serial_number()
+    }
+
+    /// product name from the edid display descriptors
+    pub fn product_name(&self) -> Option<&str> {
+        self.edid.product_name()
+    }
+
+    /// 64 bit FNV-1a hash of the edid data as read from the display, including extension blocks
+    pub fn edid_hash(&self) -> u64 {
+        let mut hash = Fnv1a::default();
+        hash.write(&self.edid_data);
+        hash.0
+    }
+
+    /// check if the edid data matches the selector, `None` for selectors depending on the
+    /// connection of the display
+    pub fn matches(&self, selector: &Selector) -> Option<bool> {
+        let header = &self.edid.header;
+        match selector {
+            Selector::Serial(serial) => Some(
+                self.serial_number() == Some(*serial)
+                    || (header.serial != 0 && header.serial.to_string() == *serial),
+            ),
+            Selector::Id(id) => Some(self.id == *id),
+            Selector::EdidHash(hash) => Some(self.edid_hash() == *hash),
+            Selector::ProductName(name) => Some(self.product_name() == Some(*name)),
+            Selector::ManufacturerProduct(vendor, product) => {
+                Some(self.vendor().id == *vendor && header.product == *product)
+            }
+            Selector::Connector(_) | Selector::I2cBus(_) => None,
+        }
+    }
+
     pub fn capabilities(&self) -> Option<&Capabilities> {
         self.mccs_features.as_ref()
     }
+
+    pub fn firmware(&self) -> Option<&MonitorFirmwareInfo> {
+        self.firmware.as_ref()
+    }
+
+    pub fn edid(&self) -> &Edid {
+        &self.edid
+    }
+
+    /// check if the display reports support for a power mode in its edid DPMS flags
+    pub fn supports_power_mode(&self, mode: PowerMode) -> bool {
+        let features = &self.edid.display.features;
+        match mode {
+            PowerMode::On => true,
+            PowerMode::Standby => features.dpms_standby,
+            PowerMode::Suspend => features.dpms_suspend,
+            PowerMode::Off | PowerMode::PowerOffButton => features.dpms_active_off,
+            PowerMode::Reserved(_) => false,
+        }
+    }
+}
+
+/// Criteria to look up a monitor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Selector<'a> {
+    /// connector name, e.g. "DP-1"
+    Connector(&'a str),
+    /// serial number string of the edid or the numeric edid serial number
+    Serial(&'a str),
+    /// product name of the edid, e.g. "DELL U2415"
+    ProductName(&'a str),
+    /// PNP vendor id and product code of the edid, e.g. ("DEL", 0xa0c4)
+    ManufacturerProduct(&'a str, u16),
+    /// number of the i2c bus, e.g. 4 for "/dev/i2c-4"
+    I2cBus(usize),
+    /// stable identity of the display
+    Id(MonitorId),
+    /// hash of the edid data, see [`MonitorInfo::edid_hash`]
+    EdidHash(u64),
+}
+
+/// Stable identity of a display.
+///
+/// The id is derived from the vendor, product code and serial numbers of the edid, so a display
+/// keeps its id when it is connected to a different port. Displays without serial numbers are
+/// additionally identified by the connector they are attached to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MonitorId(pub u64);
+
+impl MonitorId {
+    /// compute the id of a display, the connector path is only used for displays without serial
+    /// numbers and has to be stable across reboots, see [`DdcDevice::connector_path`]
+    pub fn new(edid: &Edid, connector_path: Option<&str>) -> Self {
+        let header = &edid.header;
+        let serial_number = edid.serial_number().filter(|serial| !serial.is_empty());
+        let mut hash = Fnv1a::default();
+        hash.write(header.vendor.iter().collect::<String>().as_bytes());
+        hash.write(&header.product.to_le_bytes());
+        hash.write(&header.serial.to_le_bytes());
+        hash.write(serial_number.unwrap_or_default().as_bytes());
+        if header.serial == 0 && serial_number.is_none() {
+            hash.write(connector_path.unwrap_or_default().as_bytes());
+        }
+        Self(hash.0)
+    }
+}
+
+impl Display for MonitorId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// 64 bit FNV-1a hash, the result has to be stable across builds so the std hashers can not be
+/// used
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+/// Manufacturer of a display.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MonitorVendor {
+    /// three letter PNP id, e.g. "DEL"
+    pub id: String,
+    /// registered company name, `None` if the id is not a known display vendor
+    pub name: Option<&'static str>,
+}
+
+impl Display for MonitorVendor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "{name} ({})", self.id),
+            None => write!(f, "{}", self.id),
+        }
+    }
+}
+
+/// Usage and firmware information reported by the display controller.
+///
+/// Features that are not supported by the display are `None`.
+#[derive(Clone, Debug, Default)]
+pub struct MonitorFirmwareInfo {
+    /// accumulated usage time of the display in hours
+    pub usage_hours: Option<u32>,
+    pub controller: Option<DisplayControllerTypeValue>,
+    pub firmware_level: Option<DisplayFirmwareLevelValue>,
+    /// MCCS version implemented by the display
+    pub vcp_version: Option<Version>,
 }
 
 /// An active handle to a connected display.
@@ -68,12 +261,26 @@ where
     pub info: MonitorInfo,
 }
 
+impl<D> std::fmt::Debug for MonitorDevice<D>
+where
+    D: Ddc,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MonitorDevice")
+            .field("handle", &self.handle.name())
+            .field("info", &self.info)
+            .finish()
+    }
+}
+
 impl<D> Display for MonitorDevice<D>
 where
     D: Ddc,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(&format!("Monitor ({})", self.handle.name()))
+            .field("id", &self.info.id().to_string())
+            .field("vendor", &self.info.vendor().to_string())
             .field("serial", &self.info.serial())
             .field("manufacture_year", &self.info.manufacture_year())
             .finish()
@@ -86,13 +293,54 @@ where
 {
     /// Create a new display from the specified handle.
     pub fn new(mut handle: D) -> Result<Self, DisplayError> {
-        let edid = handle.read_edid()?;
+        let edid_data = handle.read_edid_data()?;
+        let edid = parse_edid(&edid_data).map_err(DdcError::from)?;
         Ok(MonitorDevice {
-            handle: Box::new(handle),
             info: MonitorInfo {
+                id: MonitorId::new(&edid, handle.connector_path().as_deref()),
                 edid,
+                edid_data,
                 mccs_features: None,
+                firmware: None,
             },
+            handle: Box::new(handle),
+        })
+    }
+
+    /// read the monitor capabilities, the result is cached after the first successful read
+    pub fn read_capabilities(&mut self) -> Result<&Capabilities, DdcError> {
+        let capabilities = match self.info.mccs_features.take() {
+            Some(capabilities) => capabilities,
+            None => self.handle.read_capabilities()?,
+        };
+        Ok(self.info.mccs_features.insert(capabilities))
+    }
+
+    /// read usage time and firmware information of the monitor, the result is cached after the
+    /// first successful read
+    pub fn read_firmware_info(&mut self) -> Result<&MonitorFirmwareInfo, DdcError> {
+        let firmware = match self.info.firmware.take() {
+            Some(firmware) => firmware,
+            None => self.query_firmware_info()?,
+        };
+        Ok(self.info.firmware.insert(firmware))
+    }
+
+    fn query_firmware_info(&mut self) -> Result<MonitorFirmwareInfo, DdcError> {
+        let vcp_version = supported(self.handle.get_vcp_feature::<VcpVersionValue>())?
+            .map(|version| version.0)
+            .or_else(|| {
+                self.info
+                    .mccs_features
+                    .as_ref()
+                    .and_then(|capabilities| capabilities.mccs_version)
+            });
+        let usage_time: Option<DisplayUsageTimeValue> = supported(self.handle.get_vcp_feature())?;
+        Ok(MonitorFirmwareInfo {
+            usage_hours: usage_time.map(|usage| usage.hours(&vcp_version.unwrap_or_default())),
+            controller: supported(self.handle.get_vcp_feature())?,
+            firmware_level: supported(self.handle.get_vcp_feature())?,
+            vcp_version,
         })
     }
 
@@ -116,32 +364,256 @@ where
         self.handle.set_vcp_feature(language)
     }
 
+    /// read a continuous feature and map it to a value between 0 and 1
+    fn get_normalised<V: ContinuousVcpValue>(&mut self) -> Result<f64, DdcError> {
+        let value: V = self.handle.get_vcp_feature()?;
+        Ok((value.val() as f64) / value.max() as f64)
+    }
+
+    /// set a continuous feature, supplied value should be in range 0 <= val <= 1
+    fn set_normalised<V: ContinuousVcpValue>(&mut self, val: f64) -> Result<(), DdcError> {
+        assert!((0. ..=1.).contains(&val));
+        let value: V = self.handle.get_vcp_feature()?;
+        let max = value.max() as f64;
+        self.handle
+            .set_vcp_feature(value.with_val((max * val).round() as u16))
+    }
+
     /// read the current monitor brightness and map it to a value between 0 and 1
     pub fn get_luminance(&mut self) -> Result<f64, DdcError> {
-        let luminance: LuminanceValue = self.handle.get_vcp_feature()?;
-        Ok((luminance.val as f64) / luminance.max as f64)
+        self.get_normalised::<LuminanceValue>()
     }
 
     /// set the current monitor brightness, supplied value should be in range 0 <= val <= 1
     pub fn set_luminance(&mut self, lum: f64) -> Result<(), DdcError> {
-        assert!(lum >= 0. && lum <= 1.);
-        let mut luminance: LuminanceValue = self.handle.get_vcp_feature()?;
-        luminance.val = ((luminance.max as f64) * lum).round() as u16;
-        self.handle.set_vcp_feature(luminance)
+        self.set_normalised::<LuminanceValue>(lum)
     }
 
     /// read the current monitor contrast and map it to a value between 0 and 1
     pub fn get_contrast(&mut self) -> Result<f64, DdcError> {
-        let contrast: ContrastValue = self.handle.get_vcp_feature()?;
-        Ok((contrast.val as f64) / contrast.max as f64)
+        self.get_normalised::<ContrastValue>()
     }
 
     /// set the current monitor contrast, supplied value should be in range 0 <= val <= 1
     pub fn set_contrast(&mut self, lum: f64) -> Result<(), DdcError> {
-        assert!(lum >= 0. && lum <= 1.);
-        let mut contrast: ContrastValue = self.handle.get_vcp_feature()?;
-        contrast.val = ((contrast.max as f64) * lum).round() as u16;
-        self.handle.set_vcp_feature(contrast)
+        self.set_normalised::<ContrastValue>(lum)
+    }
+
+    /// read the current red video gain and map it to a value between 0 and 1
+    pub fn get_video_gain_red(&mut self) -> Result<f64, DdcError> {
+        self.get_normalised::<VideoGainRedValue>()
+    }
+
+    /// set the red video gain, supplied value should be in range 0 <= val <= 1
+    pub fn set_video_gain_red(&mut self, val: f64) -> Result<(), DdcError> {
+        self.set_normalised::<VideoGainRedValue>(val)
+    }
+
+    /// read the current green video gain and map it to a value between 0 and 1
+    pub fn get_video_gain_green(&mut self) -> Result<f64, DdcError> {
+        self.get_normalised::<VideoGainGreenValue>()
+    }
+
+    /// set the green video gain, supplied value should be in range 0 <= val <= 1
+    pub fn set_video_gain_green(&mut self, val: f64) -> Result<(), DdcError> {
+        self.set_normalised::<VideoGainGreenValue>(val)
+    }
+
+    /// read the current blue video gain and map it to a value between 0 and 1
+    pub fn get_video_gain_blue(&mut self) -> Result<f64, DdcError> {
+        self.get_normalised::<VideoGainBlueValue>()
+    }
+
+    /// set the blue video gain, supplied value should be in range 0 <= val <= 1
+    pub fn set_video_gain_blue(&mut self, val: f64) -> Result<(), DdcError> {
+        self.set_normalised::<VideoGainBlueValue>(val)
+    }
+
+    /// read the current red black level and map it to a value between 0 and 1
+    pub fn get_black_level_red(&mut self) -> Result<f64, DdcError> {
+        self.get_normalised::<VideoBlackLevelRedValue>()
+    }
+
+    /// set the red black level, supplied value should be in range 0 <= val <= 1
+    pub fn set_black_level_red(&mut self, val: f64) -> Result<(), DdcError> {
+        self.set_normalised::<VideoBlackLevelRedValue>(val)
+    }
+
+    /// read the current green black level and map it to a value between 0 and 1
+    pub fn get_black_level_green(&mut self) -> Result<f64, DdcError> {
+        self.get_normalised::<VideoBlackLevelGreenValue>()
+    }
+
+    /// set the green black level, supplied value should be in range 0 <= val <= 1
+    pub fn set_black_level_green(&mut self, val: f64) -> Result<(), DdcError> {
+        self.set_normalised::<VideoBlackLevelGreenValue>(val)
+    }
+
+    /// read the current blue black level and map it to a value between 0 and 1
+    pub fn get_black_level_blue(&mut self) -> Result<f64, DdcError> {
+        self.get_normalised::<VideoBlackLevelBlueValue>()
+    }
+
+    /// set the blue black level, supplied value should be in range 0 <= val <= 1
+    pub fn set_black_level_blue(&mut self, val: f64) -> Result<(), DdcError> {
+        self.set_normalised::<VideoBlackLevelBlueValue>(val)
+    }
+
+    /// get the current power mode of the monitor
+    ///
+    /// Many displays stop answering DDC/CI requests once they entered a power saving mode, in
+    /// that case [`DdcError::DisplayAsleep`] is returned. Such displays either do not acknowledge
+    /// the request at all or keep replying with null messages.
+    pub fn get_power_mode(&mut self) -> Result<PowerMode, DdcError> {
+        match self.handle.get_vcp_feature() {
+            Err(DdcError::CommunicationError(
+                DdcCiError::NoAcknowledge(_) | DdcCiError::NullResponse,
+            )) => Err(DdcError::DisplayAsleep),
+            result => result,
+        }
+    }
+
+    /// set the power mode of the monitor, support for a mode can be checked with
+    /// [`MonitorInfo::supports_power_mode`]
+    pub fn set_power_mode(&mut self, power_mode: PowerMode) -> Result<(), DdcError> {
+        self.handle.set_vcp_feature(power_mode)
+    }
+
+    /// read the current speaker volume and map it to a value between 0 and 1
+    pub fn get_volume(&mut self) -> Result<f64, DdcError> {
+        self.get_normalised::<AudioSpeakerVolumeValue>()
+    }
+
+    /// set the speaker volume, supplied value should be in range 0 <= val <= 1
+    pub fn set_volume(&mut self, volume: f64) -> Result<(), DdcError> {
+        self.set_normalised::<AudioSpeakerVolumeValue>(volume)
+    }
+
+    /// check if the monitor audio is muted, reserved values like the 0x00 reported by some
+    /// displays are returned as [`DdcError::UnexpectedVcpValue`]
+    pub fn get_audio_mute(&mut self) -> Result<bool, DdcError> {
+        match self.handle.get_vcp_feature()? {
+            AudioMute::Muted => Ok(true),
+            AudioMute::Unmuted => Ok(false),
+            AudioMute::Reserved(value) => Err(DdcError::UnexpectedVcpValue {
+                code: VcpFeatureCode::AudioMuteScreenBlank,
+                value,
+            }),
+        }
+    }
+
+    /// mute or unmute the monitor audio
+    pub fn set_audio_mute(&mut self, mute: bool) -> Result<(), DdcError> {
+        self.handle.set_vcp_feature(if mute {
+            AudioMute::Muted
+        } else {
+            AudioMute::Unmuted
+        })
+    }
+
+    /// toggle the monitor audio mute, returns the new mute state
+    ///
+    /// Nothing is written if the current mute state is unknown.
+    pub fn toggle_audio_mute(&mut self) -> Result<bool, DdcError> {
+        let mute = !self.get_audio_mute()?;
+        self.set_audio_mute(mute)?;
+        Ok(mute)
+    }
+
+    /// get the current audio processor mode
+    pub fn get_audio_processor_mode(&mut self) -> Result<AudioProcessorMode, DdcError> {
+        self.handle.get_vcp_feature()
+    }
+
+    /// set the audio processor mode
+    pub fn set_audio_processor_mode(&mut self, mode: AudioProcessorMode) -> Result<(), DdcError> {
+        self.handle.set_vcp_feature(mode)
+    }
+
+    /// restore monitor settings to their factory defaults
+    ///
+    /// ```rust,no_run
+    /// use libmonitor::{mccs::features::{ConfirmRestore, RestoreDefaults}, Monitor};
+    ///
+    /// for mut monitor in Monitor::enumerate() {
+    ///     monitor
+    ///         .restore_factory_defaults(RestoreDefaults::Factory, ConfirmRestore::confirmed())
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn restore_factory_defaults(
+        &mut self,
+        kind: RestoreDefaults,
+        confirmation: ConfirmRestore,
+    ) -> Result<(), DdcError> {
+        self.handle.restore_defaults(kind, confirmation)
+    }
+
+    /// get the currently selected color preset
+    pub fn get_color_preset(&mut self) -> Result<ColorPreset, DdcError> {
+        self.handle.get_vcp_feature()
+    }
+
+    /// select a color preset
+    pub fn set_color_preset(&mut self, preset: ColorPreset) -> Result<(), DdcError> {
+        self.handle.set_vcp_feature(preset)
+    }
+
+    /// set the color temperature closest to the requested one that the monitor supports
+    ///
+    /// Depending on the reported capabilities either a color temperature request or the nearest
+    /// color temperature preset is used. Returns the color temperature that has been applied.
+    pub fn set_color_temperature_kelvin(&mut self, kelvin: u32) -> Result<u32, DdcError> {
+        let capabilities = self.read_capabilities()?;
+        let supports_request = capabilities
+            .supports_vcp_feature(VcpFeatureCode::ColorTemperatureRequest)
+            && capabilities.supports_vcp_feature(VcpFeatureCode::UserColorTemperatureIncrement);
+        let nearest_preset = match capabilities.vcp_feature(VcpFeatureCode::SelectColorPreset) {
+            Some(VcpCapability::ColorPreset(presets)) => presets
+                .values()
+                .iter()
+                .filter_map(|preset| preset.kelvin().map(|k| (*preset, k)))
+                .min_by_key(|(_, k)| k.abs_diff(kelvin)),
+            _ => None,
+        };
+
+        let nearest_request = if supports_request {
+            let increment: ColorTemperatureIncrementValue = self.handle.get_vcp_feature()?;
+            let request: ColorTemperatureRequestValue = self.handle.get_vcp_feature()?;
+            if increment.val > 0 {
+                let increment = increment.val as u32;
+                let steps = (kelvin.saturating_sub(COLOR_TEMPERATURE_REQUEST_BASE_KELVIN) as f64
+                    / increment as f64)
+                    .round() as u32;
+                let steps = steps.min(request.max as u32);
+                Some((
+                    request.with_val(steps as u16),
+                    COLOR_TEMPERATURE_REQUEST_BASE_KELVIN + steps * increment,
+                ))
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        match (nearest_request, nearest_preset) {
+            (Some((_, request_kelvin)), Some((preset, preset_kelvin)))
+                if preset_kelvin.abs_diff(kelvin) < request_kelvin.abs_diff(kelvin) =>
+            {
+                self.handle.set_vcp_feature(preset)?;
+                Ok(preset_kelvin)
+            }
+            (Some((request, request_kelvin)), _) => {
+                self.handle.set_vcp_feature(request)?;
+                Ok(request_kelvin)
+            }
+            (None, Some((preset, preset_kelvin))) => {
+                self.handle.set_vcp_feature(preset)?;
+                Ok(preset_kelvin)
+            }
+            (None, None) => Err(DdcError::UnsupportedVcpFeature),
+        }
     }
 }
 
@@ -164,6 +636,81 @@ impl Monitor {
             inner_iter: Box::new(LinuxDdcDeviceEnumerator::iter()),
         }
     }
+
+    #[cfg(target_os = "linux")]
+    /// Find the first attached monitor matching the selector
+    ///
+    /// ```rust,no_run
+    /// use libmonitor::{Monitor, Selector};
+    ///
+    /// if let Some(mut monitor) = Monitor::find(Selector::Connector("DP-1")) {
+    ///     monitor.set_luminance(0.8).unwrap();
+    /// }
+    /// ```
+    pub fn find(selector: Selector) -> Option<Monitor> {
+        Self::enumerate().find(|monitor| monitor.matches(&selector))
+    }
+
+    #[cfg(target_os = "linux")]
+    /// Find all attached monitors matching the selector
+    pub fn find_all(selector: Selector) -> Vec<Monitor> {
+        Self::enumerate()
+            .filter(|monitor| monitor.matches(&selector))
+            .collect()
+    }
+
+    #[cfg(target_os = "linux")]
+    /// check if the monitor matches the selector
+    pub fn matches(&self, selector: &Selector) -> bool {
+        match selector {
+            Selector::Connector(connector) => self.handle.name() == *connector,
+            Selector::I2cBus(bus) => self.handle.i2c_sysnum() == *bus,
+            selector => self.info.matches(selector).unwrap_or_default(),
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    /// Enumerate all candidate i2c buses, buses that are not used as a monitor are reported with
+    /// the reason they have been skipped
+    ///
+    /// ```rust,no_run
+    /// use libmonitor::Monitor;
+    ///
+    /// for candidate in Monitor::enumerate_detailed().unwrap() {
+    ///     match candidate {
+    ///         Ok(monitor) => println!("{monitor:#}"),
+    ///         Err(skipped) => println!("skipped {skipped}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn enumerate_detailed(
+    ) -> Result<impl Iterator<Item = Result<Monitor, SkippedBus>>, DisplayError> {
+        Ok(LinuxDdcDeviceEnumerator::detailed_with(|device| {
+            MonitorDevice::new(device).map_err(skip_reason)
+        })?
+        .into_iter())
+    }
+}
+
+#[cfg(target_os = "linux")]
+/// reason for skipping a bus whose display could not be opened
+fn skip_reason(err: DisplayError) -> SkipReason {
+    let io_error = match &err {
+        DisplayError::IoError(err) | DisplayError::DdcError(DdcError::ReadDataError(err)) => {
+            Some(err)
+        }
+        DisplayError::DdcError(DdcError::CommunicationError(DdcCiError::OpenError(err))) => {
+            err.downcast_ref::<io::Error>()
+        }
+        _ => None,
+    };
+    if io_error.is_some_and(|err| err.kind() == io::ErrorKind::PermissionDenied) {
+        SkipReason::PermissionDenied
+    } else if matches!(err, DisplayError::DdcError(DdcError::EdidParseError(_))) {
+        SkipReason::EdidUnreadable(anyhow::Error::new(err))
+    } else {
+        SkipReason::Io(anyhow::Error::new(err))
+    }
 }
 
 pub struct MonitorIterator<D>
@@ -179,9 +726,418 @@ where
 {
     type Item = MonitorDevice<D>;
 
+    /// displays that can not be opened are skipped, see `Monitor::enumerate_detailed` for the
+    /// reasons
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner_iter
-            .next()
-            .and_then(|dev| MonitorDevice::new(dev).ok())
+        for dev in self.inner_iter.by_ref() {
+            let name = dev.name();
+            match MonitorDevice::new(dev) {
+                Ok(monitor) => return Some(monitor),
+                Err(err) => log::debug!("skipping display {name}: {err}"),
+            }
+        }
+        None
+    }
+}
+
+/// map unsupported vcp features to `None`
+fn supported<V>(result: Result<V, DdcError>) -> Result<Option<V>, DdcError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(DdcError::UnsupportedVcpFeature) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ddc::{
+            ci::{DdcCiMessage, DdcOpcode},
+            edid::{Descriptor, EdidBuilder},
+            mock::MockDdcDevice,
+            DdcCiError, DdcError,
+        },
+        mccs::{
+            capabilities::parse_capabilities,
+            features::{AudioProcessorMode, PowerMode},
+        },
+        MonitorDevice, MonitorId,
+    };
+
+    fn power_mode_reply(mode: u8) -> DdcCiMessage {
+        DdcCiMessage::from_opcode(DdcOpcode::VcpReply)
+            .set_data(&[0x00, 0xd6, 0x00, 0x00, 0x05, 0x00, mode])
+            .unwrap()
+    }
+
+    fn vcp_reply(code: u8, max: u16, value: u16) -> DdcCiMessage {
+        let [mh, ml] = max.to_be_bytes();
+        let [vh, vl] = value.to_be_bytes();
+        DdcCiMessage::from_opcode(DdcOpcode::VcpReply)
+            .set_data(&[0x00, code, 0x00, mh, ml, vh, vl])
+            .unwrap()
+    }
+
+    fn vcp_request(code: u8) -> Vec<u8> {
+        DdcCiMessage::from_opcode(DdcOpcode::VcpRequest)
+            .set_vcp_feature(code.into())
+            .transmit_buffer()
+    }
+
+    fn set_vcp_request(code: u8, value: u16) -> Vec<u8> {
+        DdcCiMessage::from_opcode(DdcOpcode::SetVcp)
+            .set_vcp_feature(code.into())
+            .set_data(&value.to_be_bytes())
+            .unwrap()
+            .transmit_buffer()
+    }
+
+    type Getter = fn(&mut MonitorDevice<MockDdcDevice>) -> Result<f64, DdcError>;
+    type Setter = fn(&mut MonitorDevice<MockDdcDevice>, f64) -> Result<(), DdcError>;
+
+    #[test]
+    fn color_gain_and_black_level() {
+        let accessors: [(u8, Getter, Setter); 6] = [
+            (
+                0x16,
+                MonitorDevice::get_video_gain_red,
+                MonitorDevice::set_video_gain_red,
+            ),
+            (
+                0x18,
+                MonitorDevice::get_video_gain_green,
+                MonitorDevice::set_video_gain_green,
+            ),
+            (
+                0x1a,
+                MonitorDevice::get_video_gain_blue,
+                MonitorDevice::set_video_gain_blue,
+            ),
+            (
+                0x6c,
+                MonitorDevice::get_black_level_red,
+                MonitorDevice::set_black_level_red,
+            ),
+            (
+                0x6e,
+                MonitorDevice::get_black_level_green,
+                MonitorDevice::set_black_level_green,
+            ),
+            (
+                0x70,
+                MonitorDevice::get_black_level_blue,
+                MonitorDevice::set_black_level_blue,
+            ),
+        ];
+        for (code, get, set) in accessors {
+            let device = MockDdcDevice::default()
+                .reply(vcp_reply(code, 200, 50))
+                .reply(vcp_reply(code, 200, 50));
+            let mut monitor = MonitorDevice::new(device).unwrap();
+            assert_eq!(get(&mut monitor).unwrap(), 0.25);
+            // the maximum is read again before writing the scaled value
+            set(&mut monitor, 0.5).unwrap();
+            assert_eq!(
+                monitor.handle.transmitted,
+                vec![
+                    vcp_request(code),
+                    vcp_request(code),
+                    set_vcp_request(code, 100)
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn power_mode_asleep() {
+        let device = MockDdcDevice::default().reply(power_mode_reply(0x04));
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        assert_eq!(monitor.get_power_mode().unwrap(), PowerMode::Off);
+
+        let device = MockDdcDevice::default()
+            .transmit_error(DdcCiError::NoAcknowledge(anyhow::anyhow!("nack")));
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        assert!(matches!(
+            monitor.get_power_mode(),
+            Err(DdcError::DisplayAsleep)
+        ));
+
+        let mut device = MockDdcDevice::default();
+        for _ in 0..4 {
+            device = device.reply(DdcCiMessage::NullResponse());
+        }
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        assert!(matches!(
+            monitor.get_power_mode(),
+            Err(DdcError::DisplayAsleep)
+        ));
+
+        // other communication errors are not caused by a sleeping display
+        let device = MockDdcDevice::default()
+            .receive_error(DdcCiError::ReceiveError(anyhow::anyhow!("i/o error")));
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        assert!(matches!(
+            monitor.get_power_mode(),
+            Err(DdcError::CommunicationError(DdcCiError::ReceiveError(_)))
+        ));
+        let device = MockDdcDevice::default()
+            .transmit_error(DdcCiError::OpenError(anyhow::anyhow!("permission denied")));
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        assert!(matches!(
+            monitor.get_power_mode(),
+            Err(DdcError::CommunicationError(DdcCiError::OpenError(_)))
+        ));
+    }
+
+    #[test]
+    fn monitor_id_stability() {
+        let monitor = EdidBuilder::new()
+            .vendor(['D', 'E', 'L'])
+            .product(0xa0c4)
+            .descriptor(Descriptor::SerialNumber("ABC123".to_string()))
+            .build()
+            .unwrap();
+        assert_eq!(
+            MonitorId::new(&monitor, Some("pci-0000:03:00.0-DP-1")),
+            MonitorId::new(&monitor, Some("pci-0000:03:00.0-DP-2"))
+        );
+
+        let blank_serial = EdidBuilder::new()
+            .vendor(['D', 'E', 'L'])
+            .product(0xa0c4)
+            .build()
+            .unwrap();
+        assert_ne!(
+            MonitorId::new(&blank_serial, Some("pci-0000:03:00.0-DP-1")),
+            MonitorId::new(&blank_serial, Some("pci-0000:03:00.0-DP-2"))
+        );
+        assert_ne!(
+            MonitorId::new(&monitor, None),
+            MonitorId::new(&blank_serial, None)
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn skip_reason_mapping() {
+        use crate::{
+            ddc::{edid::EdidParseError, linux::SkipReason},
+            skip_reason, DisplayError,
+        };
+        use std::io;
+
+        let denied = || io::Error::from(io::ErrorKind::PermissionDenied);
+        assert!(matches!(
+            skip_reason(DisplayError::IoError(denied())),
+            SkipReason::PermissionDenied
+        ));
+        assert!(matches!(
+            skip_reason(DisplayError::DdcError(DdcError::ReadDataError(denied()))),
+            SkipReason::PermissionDenied
+        ));
+        assert!(matches!(
+            skip_reason(DisplayError::DdcError(DdcError::CommunicationError(
+                DdcCiError::OpenError(anyhow::Error::new(denied()))
+            ))),
+            SkipReason::PermissionDenied
+        ));
+        assert!(matches!(
+            skip_reason(DisplayError::DdcError(DdcError::EdidParseError(
+                EdidParseError::Empty
+            ))),
+            SkipReason::EdidUnreadable(_)
+        ));
+        assert!(matches!(
+            skip_reason(DisplayError::DdcError(DdcError::ReadDataError(
+                io::Error::from(io::ErrorKind::NotFound)
+            ))),
+            SkipReason::Io(_)
+        ));
+        assert!(matches!(
+            skip_reason(DisplayError::DdcError(DdcError::CommunicationError(
+                DdcCiError::ReceiveError(anyhow::anyhow!("i/o error"))
+            ))),
+            SkipReason::Io(_)
+        ));
+    }
+
+    #[test]
+    fn monitor_info_matches() {
+        use crate::{MonitorInfo, Selector};
+
+        let edid = EdidBuilder::new()
+            .vendor(['D', 'E', 'L'])
+            .product(0xa0c4)
+            .serial(1234)
+            .descriptor(Descriptor::SerialNumber("ABC123".to_string()))
+            .descriptor(Descriptor::ProductName("DELL U2415".to_string()))
+            .build()
+            .unwrap();
+        let info = MonitorInfo {
+            id: MonitorId::new(&edid, None),
+            edid_data: edid.to_bytes(),
+            edid,
+            mccs_features: None,
+            firmware: None,
+        };
+        assert_eq!(info.matches(&Selector::Serial("ABC123")), Some(true));
+        assert_eq!(info.matches(&Selector::Serial("1234")), Some(true));
+        assert_eq!(info.matches(&Selector::Serial("4321")), Some(false));
+        assert_eq!(
+            info.matches(&Selector::ProductName("DELL U2415")),
+            Some(true)
+        );
+        assert_eq!(info.matches(&Selector::ProductName("DELL")), Some(false));
+        assert_eq!(
+            info.matches(&Selector::ManufacturerProduct("DEL", 0xa0c4)),
+            Some(true)
+        );
+        assert_eq!(
+            info.matches(&Selector::ManufacturerProduct("DEL", 0xa0c5)),
+            Some(false)
+        );
+        assert_eq!(info.matches(&Selector::Id(info.id())), Some(true));
+        assert_eq!(
+            info.matches(&Selector::EdidHash(info.edid_hash())),
+            Some(true)
+        );
+        assert_eq!(info.matches(&Selector::EdidHash(0)), Some(false));
+        assert_eq!(info.matches(&Selector::Connector("DP-1")), None);
+        assert_eq!(info.matches(&Selector::I2cBus(4)), None);
+    }
+
+    #[test]
+    fn edid_hash_of_raw_data() {
+        use crate::{
+            ddc::edid::{parse_edid, test::EDID_BASE_BLOCK},
+            MonitorInfo,
+        };
+
+        // the reserved byte of the serial number descriptor is not kept by the parser
+        let mut data = EDID_BASE_BLOCK;
+        data[76] = 0x01;
+        data[127] = data[127].wrapping_sub(0x01);
+        let edid = parse_edid(&data).unwrap();
+        assert_eq!(edid, parse_edid(&EDID_BASE_BLOCK).unwrap());
+        let info = |edid_data: &[u8]| MonitorInfo {
+            id: MonitorId::new(&edid, None),
+            edid: edid.clone(),
+            edid_data: edid_data.to_vec(),
+            mccs_features: None,
+            firmware: None,
+        };
+        assert_ne!(info(&data).edid_hash(), info(&EDID_BASE_BLOCK).edid_hash());
+        assert_ne!(info(&data).edid_hash(), info(&edid.to_bytes()).edid_hash());
+    }
+
+    /// monitor with cached capabilities, so only the queued vcp replies are read
+    fn monitor_with_capabilities(
+        capabilities: &str,
+        device: MockDdcDevice,
+    ) -> MonitorDevice<MockDdcDevice> {
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        monitor.info.mccs_features = Some(parse_capabilities(capabilities).unwrap());
+        monitor
+    }
+
+    /// replies to the color temperature increment and request reads
+    fn color_temperature_device(increment: u16, max: u16) -> MockDdcDevice {
+        MockDdcDevice::default()
+            .reply(vcp_reply(0x0b, 0xffff, increment))
+            .reply(vcp_reply(0x0c, max, 0))
+    }
+
+    #[test]
+    fn color_temperature_kelvin() {
+        const PRESETS_AND_REQUEST: &str = "(vcp(0B 0C 14(05 08)))";
+
+        // the nearest preset is closer than the largest request
+        let mut monitor =
+            monitor_with_capabilities(PRESETS_AND_REQUEST, color_temperature_device(100, 30));
+        assert_eq!(monitor.set_color_temperature_kelvin(6400).unwrap(), 6500);
+        assert_eq!(
+            monitor.handle.transmitted.last(),
+            Some(&set_vcp_request(0x14, 0x05))
+        );
+
+        // requests are clamped to the maximum reported by the display
+        let mut monitor =
+            monitor_with_capabilities("(vcp(0B 0C))", color_temperature_device(100, 10));
+        assert_eq!(monitor.set_color_temperature_kelvin(5000).unwrap(), 4000);
+        assert_eq!(
+            monitor.handle.transmitted.last(),
+            Some(&set_vcp_request(0x0c, 10))
+        );
+
+        // requests are not used without a valid increment
+        let mut monitor =
+            monitor_with_capabilities(PRESETS_AND_REQUEST, color_temperature_device(0, 30));
+        assert_eq!(monitor.set_color_temperature_kelvin(5000).unwrap(), 6500);
+        assert_eq!(
+            monitor.handle.transmitted.last(),
+            Some(&set_vcp_request(0x14, 0x05))
+        );
+
+        // requests are preferred if the preset is not closer
+        let mut monitor =
+            monitor_with_capabilities(PRESETS_AND_REQUEST, color_temperature_device(1000, 3));
+        assert_eq!(monitor.set_color_temperature_kelvin(6250).unwrap(), 6000);
+        assert_eq!(
+            monitor.handle.transmitted.last(),
+            Some(&set_vcp_request(0x0c, 3))
+        );
+
+        let mut monitor = monitor_with_capabilities("(vcp(14(01 02)))", MockDdcDevice::default());
+        assert!(matches!(
+            monitor.set_color_temperature_kelvin(6500),
+            Err(DdcError::UnsupportedVcpFeature)
+        ));
+        assert!(monitor.handle.transmitted.is_empty());
+    }
+
+    #[test]
+    fn audio_controls() {
+        let device = MockDdcDevice::default()
+            .reply(vcp_reply(0x62, 100, 25))
+            .reply(vcp_reply(0x62, 100, 25));
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        assert_eq!(monitor.get_volume().unwrap(), 0.25);
+        monitor.set_volume(0.8).unwrap();
+        assert_eq!(
+            monitor.handle.transmitted.last(),
+            Some(&set_vcp_request(0x62, 80))
+        );
+
+        let device = MockDdcDevice::default().reply(vcp_reply(0x8d, 2, 0x02));
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        assert!(monitor.toggle_audio_mute().unwrap());
+        assert_eq!(
+            monitor.handle.transmitted,
+            vec![vcp_request(0x8d), set_vcp_request(0x8d, 0x01)]
+        );
+
+        // the mute state is unknown for reserved values, toggling must not guess it
+        let device = MockDdcDevice::default().reply(vcp_reply(0x8d, 2, 0x00));
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        assert!(matches!(
+            monitor.toggle_audio_mute(),
+            Err(DdcError::UnexpectedVcpValue { value: 0x00, .. })
+        ));
+        assert_eq!(monitor.handle.transmitted, vec![vcp_request(0x8d)]);
+
+        let device = MockDdcDevice::default().reply(vcp_reply(0x94, 0xff, 0x25));
+        let mut monitor = MonitorDevice::new(device).unwrap();
+        assert_eq!(
+            monitor.get_audio_processor_mode().unwrap(),
+            AudioProcessorMode::Dolby(5)
+        );
+        monitor
+            .set_audio_processor_mode(AudioProcessorMode::Stereo)
+            .unwrap();
+        assert_eq!(
+            monitor.handle.transmitted.last(),
+            Some(&set_vcp_request(0x94, 0x02))
+        );
     }
 }
@@ -7,7 +7,10 @@ mod parsers;
 use crate::ddc::ci::DdcOpcode;
 
 use self::{entries::ValueParser, parsers::Cap};
-use super::{features::VcpCapability, DisplayTechnology, Protocol};
+use super::{
+    features::{VcpCapability, VcpFeatureCode},
+    DisplayTechnology, Protocol,
+};
 
 use {
     crate::mccs::{UnknownData, UnknownTag, Version},
@@ -37,6 +40,20 @@ pub struct Capabilities {
     pub unknown_tags: Vec<UnknownTag>,
 }
 
+impl Capabilities {
+    /// Look up the capability entry of a VCP feature.
+    pub fn vcp_feature(&self, code: VcpFeatureCode) -> Option<&VcpCapability> {
+        self.vcp_features
+            .iter()
+            .find(|capability| capability.feature_code() == code)
+    }
+
+    /// Check if the display reports support for a VCP feature.
+    pub fn supports_vcp_feature(&self, code: VcpFeatureCode) -> bool {
+        self.vcp_feature(code).is_some()
+    }
+}
+
 /// Parses a MCCS capability string.
 pub fn parse_capabilities<C: AsRef<[u8]>>(capability_string: C) -> io::Result<Capabilities> {
     let capability_string = capability_string.as_ref();
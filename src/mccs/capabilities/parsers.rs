@@ -34,7 +34,7 @@ impl<'i> Cap<'i> {
     ) -> impl Iterator<Item = io::Result<Cap<'i>>> + 'i {
         entries
             .nom_iter()
-            .map(|e| e.and_then(|e| Self::parse_entry(e)).map_err(map_err))
+            .map(|e| e.and_then(Self::parse_entry).map_err(map_err))
     }
 
     pub fn parse_entry(value: Value<'i>) -> OResult<'i, Cap<'i>> {
@@ -45,9 +45,7 @@ impl<'i> Cap<'i> {
     }
 
     pub fn parse_data(tag: &'i str, i: &'i [u8]) -> Cap<'i> {
-        match tag {
-            _ => Cap::Unknown(Value::Binary { tag, data: i }),
-        }
+        Cap::Unknown(Value::Binary { tag, data: i })
     }
 
     pub fn parse_string(tag: &'i str, i: &'i [u8]) -> OResult<'i, Cap<'i>> {
@@ -96,7 +94,7 @@ where
     use nom::Parser;
 
     let mut f = map_res(rest, f);
-    let (i, s) = map_res(|i| parser.parse(i), |i| str::from_utf8(i.into()))(i)?;
+    let (i, s) = map_res(|i| parser.parse(i), str::from_utf8)(i)?;
     match f.parse(s) {
         Ok((_, v)) => Ok((i, v)),
         Err(e) => Err(e.map(|e: nom::error::Error<_>| nom::error::Error {
@@ -144,3 +142,28 @@ fn mccs_ver(i: &[u8]) -> IResult<&[u8], (u8, u8)> {
         tuple((map_parser(take(2usize), u8), map_parser(take(2usize), u8))),
     ))(i)
 }
+
+#[test]
+fn vcp_feature_mapping() {
+    let (_, caps) = many0(vcp)(b"10 14(05 08) 73 E0(01)").unwrap();
+
+    let mut color_preset = VcpCapability::from_feature_code(VcpFeatureCode::SelectColorPreset)
+        .expect("select color preset is a standard feature");
+    color_preset.add_discrete_value(0x05);
+    color_preset.add_discrete_value(0x08);
+    let mut vendor_specific = DiscreteValues::default();
+    vendor_specific.add_discrete_value(0x01);
+
+    assert_eq!(
+        caps,
+        vec![
+            VcpCapability::Continuous(VcpFeatureCode::Luminance),
+            color_preset,
+            VcpCapability::Table(VcpFeatureCode::LutSize),
+            VcpCapability::UnimplementedDiscrete((
+                VcpFeatureCode::VendorSpecific(0xe0),
+                vendor_specific
+            )),
+        ]
+    );
+}
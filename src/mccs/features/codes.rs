@@ -0,0 +1,289 @@
+//! Feature code definitions from the MCCS 2.2a and 3.0 standards
+
+use crate::mccs::Version;
+
+/// How the value of a VCP feature is to be interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VcpValueType {
+    /// the value is in the range from 0 to a maximum reported by the display
+    Continuous,
+    /// the value is one of a set of discrete values defined per feature
+    NonContinuous,
+    /// the value is a table of bytes read or written via table read/write commands
+    Table,
+}
+
+/// Access mode defined for a VCP feature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VcpAccess {
+    ReadOnly,
+    WriteOnly,
+    ReadWrite,
+}
+
+impl VcpAccess {
+    pub fn is_readable(&self) -> bool {
+        !matches!(self, VcpAccess::WriteOnly)
+    }
+
+    pub fn is_writable(&self) -> bool {
+        !matches!(self, VcpAccess::ReadOnly)
+    }
+}
+
+/// Metadata about a standardized VCP feature
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VcpFeatureInfo {
+    /// human readable feature name as used in the MCCS standard
+    pub name: &'static str,
+    pub value_type: VcpValueType,
+    pub access: VcpAccess,
+    /// MCCS versions that define the feature
+    pub versions: &'static [Version],
+}
+
+const MCCS_2_0: Version = Version { major: 2, minor: 0 };
+const MCCS_2_1: Version = Version { major: 2, minor: 1 };
+const MCCS_2_2: Version = Version { major: 2, minor: 2 };
+const MCCS_3_0: Version = Version { major: 3, minor: 0 };
+
+const ALL: &[Version] = &[MCCS_2_0, MCCS_2_1, MCCS_2_2, MCCS_3_0];
+const SINCE_2_1: &[Version] = &[MCCS_2_1, MCCS_2_2, MCCS_3_0];
+const SINCE_2_2: &[Version] = &[MCCS_2_2, MCCS_3_0];
+// features deprecated with MCCS 2.2, 3.0 was published before 2.2 and still defines them
+const EXCEPT_2_2: &[Version] = &[MCCS_2_0, MCCS_2_1, MCCS_3_0];
+
+macro_rules! vcp_feature_codes {
+    ($($variant:ident = $code:literal, $name:literal, $value_type:ident, $access:ident, $versions:ident;)*) => {
+        /// VCP feature code
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum VcpFeatureCode {
+            $($variant,)*
+            VendorSpecific(u8),
+            Unimplemented(u8),
+            Unknown,
+        }
+
+        impl From<VcpFeatureCode> for u8 {
+            fn from(value: VcpFeatureCode) -> Self {
+                match value {
+                    $(VcpFeatureCode::$variant => $code,)*
+                    VcpFeatureCode::VendorSpecific(val) => val,
+                    VcpFeatureCode::Unimplemented(val) => val,
+                    VcpFeatureCode::Unknown => 0x00,
+                }
+            }
+        }
+
+        impl From<u8> for VcpFeatureCode {
+            fn from(value: u8) -> Self {
+                match value {
+                    $($code => Self::$variant,)*
+                    0xe0..=0xff => Self::VendorSpecific(value),
+                    _ => Self::Unimplemented(value),
+                }
+            }
+        }
+
+        impl VcpFeatureCode {
+            /// standard metadata for the feature, not available for vendor specific or unknown codes
+            pub fn info(&self) -> Option<&'static VcpFeatureInfo> {
+                match self {
+                    $(Self::$variant => Some(&VcpFeatureInfo {
+                        name: $name,
+                        value_type: VcpValueType::$value_type,
+                        access: VcpAccess::$access,
+                        versions: $versions,
+                    }),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+vcp_feature_codes! {
+    Degauss = 0x01, "Degauss", NonContinuous, WriteOnly, ALL;
+    NewControlValue = 0x02, "New Control Value", NonContinuous, ReadWrite, ALL;
+    SoftControls = 0x03, "Soft Controls", NonContinuous, ReadWrite, ALL;
+    RestoreFactoryDefaults = 0x04, "Restore Factory Defaults", NonContinuous, WriteOnly, ALL;
+    RestoreFactoryLuminanceContrast = 0x05, "Restore Factory Luminance/Contrast Defaults", NonContinuous, WriteOnly, ALL;
+    RestoreFactoryGeometry = 0x06, "Restore Factory Geometry Defaults", NonContinuous, WriteOnly, ALL;
+    RestoreFactoryColor = 0x08, "Restore Factory Color Defaults", NonContinuous, WriteOnly, ALL;
+    RestoreFactoryTvDefaults = 0x0a, "Restore Factory TV Defaults", NonContinuous, WriteOnly, ALL;
+    UserColorTemperatureIncrement = 0x0b, "User Color Temperature Increment", Continuous, ReadOnly, SINCE_2_1;
+    ColorTemperatureRequest = 0x0c, "User Color Temperature", Continuous, ReadWrite, ALL;
+    Clock = 0x0e, "Clock", Continuous, ReadWrite, ALL;
+    Luminance = 0x10, "Luminance", Continuous, ReadWrite, ALL;
+    FleshToneEnhancement = 0x11, "Flesh Tone Enhancement", NonContinuous, ReadWrite, SINCE_2_1;
+    Contrast = 0x12, "Contrast", Continuous, ReadWrite, ALL;
+    BacklightControl = 0x13, "Backlight Control", Continuous, ReadWrite, EXCEPT_2_2;
+    SelectColorPreset = 0x14, "Select Color Preset", NonContinuous, ReadWrite, ALL;
+    VideoGainRed = 0x16, "Video Gain (Drive): Red", Continuous, ReadWrite, ALL;
+    UserVisionCompensation = 0x17, "User Vision Compensation", Continuous, ReadWrite, SINCE_2_1;
+    VideoGainGreen = 0x18, "Video Gain (Drive): Green", Continuous, ReadWrite, ALL;
+    VideoGainBlue = 0x1a, "Video Gain (Drive): Blue", Continuous, ReadWrite, ALL;
+    Focus = 0x1c, "Focus", Continuous, ReadWrite, ALL;
+    AutoSetup = 0x1e, "Auto Setup", NonContinuous, ReadWrite, ALL;
+    AutoColorSetup = 0x1f, "Auto Color Setup", NonContinuous, ReadWrite, ALL;
+    HorizontalPosition = 0x20, "Horizontal Position (Phase)", Continuous, ReadWrite, ALL;
+    HorizontalSize = 0x22, "Horizontal Size", Continuous, ReadWrite, ALL;
+    HorizontalPincushion = 0x24, "Horizontal Pincushion", Continuous, ReadWrite, ALL;
+    HorizontalPincushionBalance = 0x26, "Horizontal Pincushion Balance", Continuous, ReadWrite, ALL;
+    HorizontalConvergenceRB = 0x28, "Horizontal Convergence R/B", Continuous, ReadWrite, ALL;
+    HorizontalConvergenceMG = 0x29, "Horizontal Convergence M/G", Continuous, ReadWrite, ALL;
+    HorizontalLinearity = 0x2a, "Horizontal Linearity", Continuous, ReadWrite, ALL;
+    HorizontalLinearityBalance = 0x2c, "Horizontal Linearity Balance", Continuous, ReadWrite, ALL;
+    GrayScaleExpansion = 0x2e, "Gray Scale Expansion", NonContinuous, ReadWrite, SINCE_2_1;
+    VerticalPosition = 0x30, "Vertical Position (Phase)", Continuous, ReadWrite, ALL;
+    VerticalSize = 0x32, "Vertical Size", Continuous, ReadWrite, ALL;
+    VerticalPincushion = 0x34, "Vertical Pincushion", Continuous, ReadWrite, ALL;
+    VerticalPincushionBalance = 0x36, "Vertical Pincushion Balance", Continuous, ReadWrite, ALL;
+    VerticalConvergenceRB = 0x38, "Vertical Convergence R/B", Continuous, ReadWrite, ALL;
+    VerticalConvergenceMG = 0x39, "Vertical Convergence M/G", Continuous, ReadWrite, ALL;
+    VerticalLinearity = 0x3a, "Vertical Linearity", Continuous, ReadWrite, ALL;
+    VerticalLinearityBalance = 0x3c, "Vertical Linearity Balance", Continuous, ReadWrite, ALL;
+    ClockPhase = 0x3e, "Clock Phase", Continuous, ReadWrite, ALL;
+    HorizontalParallelogram = 0x40, "Horizontal Parallelogram", Continuous, ReadWrite, ALL;
+    VerticalParallelogram = 0x41, "Vertical Parallelogram", Continuous, ReadWrite, ALL;
+    HorizontalKeystone = 0x42, "Horizontal Keystone", Continuous, ReadWrite, ALL;
+    VerticalKeystone = 0x43, "Vertical Keystone", Continuous, ReadWrite, ALL;
+    Rotation = 0x44, "Rotation", Continuous, ReadWrite, ALL;
+    TopCornerFlare = 0x46, "Top Corner Flare", Continuous, ReadWrite, ALL;
+    TopCornerHook = 0x48, "Top Corner Hook", Continuous, ReadWrite, ALL;
+    BottomCornerFlare = 0x4a, "Bottom Corner Flare", Continuous, ReadWrite, ALL;
+    BottomCornerHook = 0x4c, "Bottom Corner Hook", Continuous, ReadWrite, ALL;
+    ActiveControl = 0x52, "Active Control", NonContinuous, ReadOnly, ALL;
+    PerformancePreservation = 0x54, "Performance Preservation", NonContinuous, ReadWrite, SINCE_2_2;
+    HorizontalMoire = 0x56, "Horizontal Moire", Continuous, ReadWrite, ALL;
+    VerticalMoire = 0x58, "Vertical Moire", Continuous, ReadWrite, ALL;
+    SixAxisSaturationRed = 0x59, "6 Axis Saturation Control: Red", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisSaturationYellow = 0x5a, "6 Axis Saturation Control: Yellow", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisSaturationGreen = 0x5b, "6 Axis Saturation Control: Green", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisSaturationCyan = 0x5c, "6 Axis Saturation Control: Cyan", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisSaturationBlue = 0x5d, "6 Axis Saturation Control: Blue", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisSaturationMagenta = 0x5e, "6 Axis Saturation Control: Magenta", Continuous, ReadWrite, SINCE_2_2;
+    InputSelect = 0x60, "Input Select", NonContinuous, ReadWrite, ALL;
+    AudioSpeakerVolume = 0x62, "Audio Speaker Volume", Continuous, ReadWrite, ALL;
+    SpeakerSelect = 0x63, "Speaker Select", NonContinuous, ReadWrite, SINCE_2_2;
+    AudioMicrophoneVolume = 0x64, "Audio Microphone Volume", Continuous, ReadWrite, ALL;
+    AmbientLightSensor = 0x66, "Ambient Light Sensor", NonContinuous, ReadWrite, SINCE_2_2;
+    BacklightLevelWhite = 0x6b, "Backlight Level: White", Continuous, ReadWrite, SINCE_2_2;
+    VideoBlackLevelRed = 0x6c, "Video Black Level: Red", Continuous, ReadWrite, ALL;
+    BacklightLevelRed = 0x6d, "Backlight Level: Red", Continuous, ReadWrite, SINCE_2_2;
+    VideoBlackLevelGreen = 0x6e, "Video Black Level: Green", Continuous, ReadWrite, ALL;
+    BacklightLevelGreen = 0x6f, "Backlight Level: Green", Continuous, ReadWrite, SINCE_2_2;
+    VideoBlackLevelBlue = 0x70, "Video Black Level: Blue", Continuous, ReadWrite, ALL;
+    BacklightLevelBlue = 0x71, "Backlight Level: Blue", Continuous, ReadWrite, SINCE_2_2;
+    Gamma = 0x72, "Gamma", NonContinuous, ReadWrite, SINCE_2_2;
+    LutSize = 0x73, "LUT Size", Table, ReadOnly, ALL;
+    SinglePointLutOperation = 0x74, "Single Point LUT Operation", Table, ReadWrite, ALL;
+    BlockLutOperation = 0x75, "Block LUT Operation", Table, ReadWrite, ALL;
+    RemoteProcedureCall = 0x76, "Remote Procedure Call", Table, WriteOnly, ALL;
+    DisplayIdentificationOperation = 0x78, "Display Identification Data Operation", Table, ReadOnly, SINCE_2_2;
+    AdjustFocalPlane = 0x7a, "Adjust Focal Plane", Continuous, ReadWrite, ALL;
+    AdjustZoom = 0x7c, "Adjust Zoom", Continuous, ReadWrite, ALL;
+    Trapezoid = 0x7e, "Trapezoid", Continuous, ReadWrite, ALL;
+    Keystone = 0x80, "Keystone", Continuous, ReadWrite, ALL;
+    HorizontalMirror = 0x82, "Horizontal Mirror (Flip)", NonContinuous, ReadWrite, ALL;
+    VerticalMirror = 0x84, "Vertical Mirror (Flip)", NonContinuous, ReadWrite, ALL;
+    DisplayScaling = 0x86, "Display Scaling", NonContinuous, ReadWrite, ALL;
+    Sharpness = 0x87, "Sharpness", Continuous, ReadWrite, ALL;
+    VelocityScanModulation = 0x88, "Velocity Scan Modulation", Continuous, ReadWrite, ALL;
+    ColorSaturation = 0x8a, "Color Saturation", Continuous, ReadWrite, ALL;
+    TvChannelUpDown = 0x8b, "TV Channel Up/Down", NonContinuous, WriteOnly, ALL;
+    TvSharpness = 0x8c, "TV Sharpness", Continuous, ReadWrite, ALL;
+    AudioMuteScreenBlank = 0x8d, "Audio Mute/Screen Blank", NonContinuous, ReadWrite, ALL;
+    TvContrast = 0x8e, "TV Contrast", Continuous, ReadWrite, ALL;
+    AudioTreble = 0x8f, "Audio Treble", Continuous, ReadWrite, ALL;
+    Hue = 0x90, "Hue", Continuous, ReadWrite, ALL;
+    AudioBass = 0x91, "Audio Bass", Continuous, ReadWrite, ALL;
+    TvBlackLevel = 0x92, "TV Black Level/Luminance", Continuous, ReadWrite, ALL;
+    AudioBalance = 0x93, "Audio Balance L/R", Continuous, ReadWrite, ALL;
+    AudioProcessorMode = 0x94, "Audio Processor Mode", NonContinuous, ReadWrite, ALL;
+    WindowPositionTopLeftX = 0x95, "Window Position (TL_X)", Continuous, ReadWrite, ALL;
+    WindowPositionTopLeftY = 0x96, "Window Position (TL_Y)", Continuous, ReadWrite, ALL;
+    WindowPositionBottomRightX = 0x97, "Window Position (BR_X)", Continuous, ReadWrite, ALL;
+    WindowPositionBottomRightY = 0x98, "Window Position (BR_Y)", Continuous, ReadWrite, ALL;
+    WindowControl = 0x99, "Window Control On/Off", NonContinuous, ReadWrite, SINCE_2_2;
+    WindowBackground = 0x9a, "Window Background", Continuous, ReadWrite, ALL;
+    SixAxisHueRed = 0x9b, "6 Axis Hue Control: Red", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisHueYellow = 0x9c, "6 Axis Hue Control: Yellow", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisHueGreen = 0x9d, "6 Axis Hue Control: Green", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisHueCyan = 0x9e, "6 Axis Hue Control: Cyan", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisHueBlue = 0x9f, "6 Axis Hue Control: Blue", Continuous, ReadWrite, SINCE_2_2;
+    SixAxisHueMagenta = 0xa0, "6 Axis Hue Control: Magenta", Continuous, ReadWrite, SINCE_2_2;
+    AutoSetupOnOff = 0xa2, "Auto Setup On/Off", NonContinuous, WriteOnly, ALL;
+    WindowMaskControl = 0xa4, "Window Mask Control", Table, ReadWrite, SINCE_2_2;
+    WindowSelect = 0xa5, "Change the Selected Window", NonContinuous, ReadWrite, SINCE_2_2;
+    ScreenOrientation = 0xaa, "Screen Orientation", NonContinuous, ReadOnly, ALL;
+    HorizontalFrequency = 0xac, "Horizontal Frequency", Continuous, ReadOnly, ALL;
+    VerticalFrequency = 0xae, "Vertical Frequency", Continuous, ReadOnly, ALL;
+    Settings = 0xb0, "Settings", NonContinuous, WriteOnly, ALL;
+    FlatPanelSubPixelLayout = 0xb2, "Flat Panel Sub-Pixel Layout", NonContinuous, ReadOnly, ALL;
+    SourceTimingMode = 0xb4, "Source Timing Mode", Table, ReadWrite, ALL;
+    SourceColorCoding = 0xb5, "Source Color Coding", NonContinuous, ReadWrite, SINCE_2_2;
+    DisplayTechnologyType = 0xb6, "Display Technology Type", NonContinuous, ReadOnly, ALL;
+    MonitorStatus = 0xb7, "Monitor Status", NonContinuous, ReadOnly, SINCE_2_2;
+    PacketCount = 0xb8, "Packet Count", Continuous, ReadWrite, SINCE_2_2;
+    MonitorXOrigin = 0xb9, "Monitor X Origin", Continuous, ReadWrite, SINCE_2_2;
+    MonitorYOrigin = 0xba, "Monitor Y Origin", Continuous, ReadWrite, SINCE_2_2;
+    HeaderErrorCount = 0xbb, "Header Error Count", Continuous, ReadWrite, SINCE_2_2;
+    BodyCrcErrorCount = 0xbc, "Body CRC Error Count", Continuous, ReadWrite, SINCE_2_2;
+    ClientId = 0xbd, "Client ID", Continuous, ReadWrite, SINCE_2_2;
+    LinkControl = 0xbe, "Link Control", NonContinuous, ReadWrite, SINCE_2_2;
+    DisplayUsageTime = 0xc0, "Display Usage Time", Continuous, ReadOnly, ALL;
+    DisplayDescriptorLength = 0xc2, "Display Descriptor Length", Continuous, ReadOnly, ALL;
+    TransmitDisplayDescriptor = 0xc3, "Transmit Display Descriptor", Table, ReadWrite, ALL;
+    EnableDisplayOfDisplayDescriptor = 0xc4, "Enable Display of 'Display Descriptor'", NonContinuous, ReadWrite, ALL;
+    ApplicationEnableKey = 0xc6, "Application Enable Key", NonContinuous, ReadOnly, ALL;
+    DisplayControllerType = 0xc8, "Display Controller Type", NonContinuous, ReadOnly, ALL;
+    DisplayFirmwareLevel = 0xc9, "Display Firmware Level", Continuous, ReadOnly, ALL;
+    OsdButtonControl = 0xca, "OSD/Button Control", NonContinuous, ReadWrite, ALL;
+    OsdLanguage = 0xcc, "OSD Language", NonContinuous, ReadWrite, ALL;
+    StatusIndicators = 0xcd, "Status Indicators", NonContinuous, ReadWrite, ALL;
+    AuxiliaryDisplaySize = 0xce, "Auxiliary Display Size", NonContinuous, ReadOnly, ALL;
+    AuxiliaryDisplayData = 0xcf, "Auxiliary Display Data", Table, WriteOnly, ALL;
+    OutputSelect = 0xd0, "Output Select", NonContinuous, ReadWrite, ALL;
+    AssetTag = 0xd2, "Asset Tag", Table, ReadWrite, ALL;
+    StereoVideoMode = 0xd4, "Stereo Video Mode", NonContinuous, ReadWrite, ALL;
+    PowerMode = 0xd6, "Power Mode", NonContinuous, ReadWrite, ALL;
+    AuxiliaryPowerOutput = 0xd7, "Auxiliary Power Output", NonContinuous, ReadWrite, SINCE_2_2;
+    ScanMode = 0xda, "Scan Mode", NonContinuous, ReadWrite, ALL;
+    ImageMode = 0xdb, "Image Mode", NonContinuous, ReadWrite, SINCE_2_2;
+    DisplayMode = 0xdc, "Display Application", NonContinuous, ReadWrite, ALL;
+    ScratchPad = 0xde, "Scratch Pad", NonContinuous, ReadWrite, ALL;
+    VcpVersion = 0xdf, "VCP Version", NonContinuous, ReadOnly, ALL;
+}
+
+impl VcpFeatureCode {
+    /// human readable name of the feature
+    pub fn name(&self) -> &'static str {
+        match self.info() {
+            Some(info) => info.name,
+            None => match self {
+                Self::VendorSpecific(_) => "Manufacturer Specific",
+                Self::Unimplemented(_) => "Reserved",
+                _ => "Unknown",
+            },
+        }
+    }
+
+    /// restore operations reset monitor settings to their factory defaults
+    pub fn is_restore_operation(&self) -> bool {
+        matches!(
+            self,
+            Self::RestoreFactoryDefaults
+                | Self::RestoreFactoryLuminanceContrast
+                | Self::RestoreFactoryGeometry
+                | Self::RestoreFactoryColor
+                | Self::RestoreFactoryTvDefaults
+        )
+    }
+
+    /// check if the feature is defined by the given MCCS version
+    pub fn is_defined_in(&self, version: &Version) -> bool {
+        self.info()
+            .is_some_and(|info| info.versions.contains(version))
+    }
+}
@@ -1,47 +1,15 @@
-use std::fmt::Debug;
+mod codes;
+
+use std::fmt::{Debug, Display};
 
 use thiserror::Error;
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use super::Version;
 
-/// VCP feature code
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum VcpFeatureCode {
-    Luminance,
-    Contrast,
-    OsdLanguage,
-    InputSelect,
-    VendorSpecific(u8),
-    Unimplemented(u8),
-    Unknown,
-}
-
-impl From<VcpFeatureCode> for u8 {
-    fn from(value: VcpFeatureCode) -> Self {
-        match value {
-            VcpFeatureCode::Luminance => 0x10,
-            VcpFeatureCode::Contrast => 0x12,
-            VcpFeatureCode::InputSelect => 0x60,
-            VcpFeatureCode::OsdLanguage => 0xcc,
-            VcpFeatureCode::VendorSpecific(val) => val,
-            VcpFeatureCode::Unimplemented(val) => val,
-            VcpFeatureCode::Unknown => 0x00,
-        }
-    }
-}
+pub use codes::{VcpAccess, VcpFeatureCode, VcpFeatureInfo, VcpValueType};
 
-impl From<u8> for VcpFeatureCode {
-    fn from(value: u8) -> Self {
-        match value {
-            0x10 => Self::Luminance,
-            0x12 => Self::Contrast,
-            0x60 => Self::InputSelect,
-            0xcc => Self::OsdLanguage,
-            _ => Self::Unimplemented(value),
-        }
-    }
-}
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 // ultimately Vcp Values can contain up to 4 bytes of information
 // so we require u32 here for now. Dunno if I will change this again
@@ -73,62 +41,81 @@ impl VcpValue for AnonymousVcpValue {
     }
 }
 
-#[derive(Clone, Copy)]
-pub struct LuminanceValue {
-    pub max: u16,
-    pub val: u16,
-}
-impl From<u32> for LuminanceValue {
-    fn from(value: u32) -> Self {
-        Self {
-            max: (value >> 16) as u16,
-            val: (value & 0xff) as u16,
-        }
-    }
-}
-impl From<LuminanceValue> for u32 {
-    fn from(value: LuminanceValue) -> Self {
-        (value.max as u32) << 16 | value.val as u32
-    }
-}
-impl VcpValue for LuminanceValue {
-    fn vcp_feature() -> VcpFeatureCode {
-        VcpFeatureCode::Luminance
-    }
+/// Continuous vcp values report their current value together with the maximum value supported
+/// by the display
+pub trait ContinuousVcpValue: VcpValue {
+    fn max(&self) -> u16;
+    fn val(&self) -> u16;
+    fn with_val(self, val: u16) -> Self;
 }
 
-#[derive(Clone, Copy)]
-pub struct ContrastValue {
-    pub max: u16,
-    pub val: u16,
-}
-impl ContrastValue {
-    pub fn max(&self) -> u16 {
-        self.max
-    }
-    pub fn val(&self) -> u16 {
-        self.val
-    }
-}
-impl From<u32> for ContrastValue {
-    fn from(value: u32) -> Self {
-        Self {
-            max: (value >> 16) as u16,
-            val: (value & 0xff) as u16,
+macro_rules! continuous_vcp_value {
+    ($name:ident, $code:ident) => {
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name {
+            pub max: u16,
+            pub val: u16,
         }
-    }
-}
-impl From<ContrastValue> for u32 {
-    fn from(value: ContrastValue) -> Self {
-        (value.max as u32) << 16 | value.val as u32
-    }
-}
-impl VcpValue for ContrastValue {
-    fn vcp_feature() -> VcpFeatureCode {
-        VcpFeatureCode::Contrast
-    }
+        impl $name {
+            pub fn max(&self) -> u16 {
+                self.max
+            }
+            pub fn val(&self) -> u16 {
+                self.val
+            }
+        }
+        impl From<u32> for $name {
+            fn from(value: u32) -> Self {
+                Self {
+                    max: (value >> 16) as u16,
+                    val: (value & 0xffff) as u16,
+                }
+            }
+        }
+        impl From<$name> for u32 {
+            fn from(value: $name) -> Self {
+                (value.max as u32) << 16 | value.val as u32
+            }
+        }
+        impl VcpValue for $name {
+            fn vcp_feature() -> VcpFeatureCode {
+                VcpFeatureCode::$code
+            }
+        }
+        impl ContinuousVcpValue for $name {
+            fn max(&self) -> u16 {
+                self.max
+            }
+            fn val(&self) -> u16 {
+                self.val
+            }
+            fn with_val(mut self, val: u16) -> Self {
+                self.val = val;
+                self
+            }
+        }
+    };
 }
 
+continuous_vcp_value!(LuminanceValue, Luminance);
+continuous_vcp_value!(ContrastValue, Contrast);
+continuous_vcp_value!(VideoGainRedValue, VideoGainRed);
+continuous_vcp_value!(VideoGainGreenValue, VideoGainGreen);
+continuous_vcp_value!(VideoGainBlueValue, VideoGainBlue);
+continuous_vcp_value!(VideoBlackLevelRedValue, VideoBlackLevelRed);
+continuous_vcp_value!(VideoBlackLevelGreenValue, VideoBlackLevelGreen);
+continuous_vcp_value!(VideoBlackLevelBlueValue, VideoBlackLevelBlue);
+continuous_vcp_value!(
+    ColorTemperatureIncrementValue,
+    UserColorTemperatureIncrement
+);
+continuous_vcp_value!(ColorTemperatureRequestValue, ColorTemperatureRequest);
+continuous_vcp_value!(AudioSpeakerVolumeValue, AudioSpeakerVolume);
+
+/// color temperature requests are encoded as multiples of the increment reported by the display
+/// starting from this base temperature
+pub const COLOR_TEMPERATURE_REQUEST_BASE_KELVIN: u32 = 3000;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DiscreteValues<V>
 where
@@ -155,13 +142,20 @@ where
     pub fn add_discrete_value(&mut self, val: V) {
         self.discrete_values.push(val);
     }
+
+    pub fn values(&self) -> &[V] {
+        &self.discrete_values
+    }
 }
 
 #[derive(PartialEq, Clone)]
 pub enum VcpCapability {
     Language(DiscreteValues<OsdLanguages>),
     DisplayInput(DiscreteValues<InputSource>),
+    ColorPreset(DiscreteValues<ColorPreset>),
     Continuous(VcpFeatureCode),
+    Discrete((VcpFeatureCode, DiscreteValues<AnonymousVcpValue>)),
+    Table(VcpFeatureCode),
     UnimplementedDiscrete((VcpFeatureCode, DiscreteValues<AnonymousVcpValue>)),
     Unimplemented(VcpFeatureCode),
 }
@@ -185,6 +179,18 @@ impl Debug for VcpCapability {
                 ))
                 .field("possible_values", &supported_values.discrete_values)
                 .finish(),
+            Self::ColorPreset(supported_values) => f
+                .debug_struct(&format!(
+                    "{:?} ({:x})",
+                    VcpFeatureCode::SelectColorPreset,
+                    Into::<u8>::into(VcpFeatureCode::SelectColorPreset)
+                ))
+                .field("possible_values", &supported_values.discrete_values)
+                .finish(),
+            Self::Discrete((code, supported_values)) => f
+                .debug_struct(&format!("{:?} ({:x})", code, Into::<u8>::into(*code)))
+                .field("possible_values", &supported_values.discrete_values)
+                .finish(),
             Self::UnimplementedDiscrete((code, supported_values)) => f
                 .debug_struct(&format!(
                     "VcpFeatureCode ({:x}) unimplemented!",
@@ -192,7 +198,7 @@ impl Debug for VcpCapability {
                 ))
                 .field("possible_values", &supported_values.discrete_values)
                 .finish(),
-            Self::Continuous(feature_code) => f
+            Self::Continuous(feature_code) | Self::Table(feature_code) => f
                 .debug_struct(&format!(
                     "{:?} ({:x})",
                     feature_code,
@@ -224,9 +230,28 @@ impl VcpCapability {
         match code {
             VcpFeatureCode::OsdLanguage => Ok(Self::Language(Default::default())),
             VcpFeatureCode::InputSelect => Ok(Self::DisplayInput(Default::default())),
+            VcpFeatureCode::SelectColorPreset => Ok(Self::ColorPreset(Default::default())),
             VcpFeatureCode::Unknown => Err(VcpCapabilityError::UnknownCapability),
-            VcpFeatureCode::Contrast | VcpFeatureCode::Luminance => Ok(Self::Continuous(code)),
-            _ => Err(VcpCapabilityError::UnimplementedVcpMapping),
+            _ => match code.info().map(|info| info.value_type) {
+                Some(VcpValueType::Continuous) => Ok(Self::Continuous(code)),
+                Some(VcpValueType::NonContinuous) => Ok(Self::Discrete((code, Default::default()))),
+                Some(VcpValueType::Table) => Ok(Self::Table(code)),
+                None => Err(VcpCapabilityError::UnimplementedVcpMapping),
+            },
+        }
+    }
+
+    /// the feature code this capability describes
+    pub fn feature_code(&self) -> VcpFeatureCode {
+        match self {
+            VcpCapability::Language(_) => VcpFeatureCode::OsdLanguage,
+            VcpCapability::DisplayInput(_) => VcpFeatureCode::InputSelect,
+            VcpCapability::ColorPreset(_) => VcpFeatureCode::SelectColorPreset,
+            VcpCapability::Continuous(code)
+            | VcpCapability::Table(code)
+            | VcpCapability::Unimplemented(code)
+            | VcpCapability::Discrete((code, _))
+            | VcpCapability::UnimplementedDiscrete((code, _)) => *code,
         }
     }
 
@@ -236,8 +261,10 @@ impl VcpCapability {
                 languages.add_discrete_value(value.into())
             }
             VcpCapability::DisplayInput(ref mut inputs) => inputs.add_discrete_value(value.into()),
-            VcpCapability::UnimplementedDiscrete((_, discrete_values)) => {
-                discrete_values.add_discrete_value(value.into())
+            VcpCapability::ColorPreset(ref mut presets) => presets.add_discrete_value(value.into()),
+            VcpCapability::Discrete((_, discrete_values))
+            | VcpCapability::UnimplementedDiscrete((_, discrete_values)) => {
+                discrete_values.add_discrete_value(value)
             }
             _ => { /* notihng to do here, this dose not represent discrete values*/ }
         }
@@ -469,3 +496,525 @@ impl VcpValue for InputSource {
         VcpFeatureCode::InputSelect
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColorPreset {
+    Srgb,
+    DisplayNative,
+    Temperature4000K,
+    Temperature5000K,
+    Temperature6500K,
+    Temperature7500K,
+    Temperature8200K,
+    Temperature9300K,
+    Temperature10000K,
+    Temperature11500K,
+    User1,
+    User2,
+    User3,
+    Reserved(u32),
+}
+
+impl ColorPreset {
+    /// color temperature of the preset, if the preset represents a fixed color temperature
+    pub fn kelvin(&self) -> Option<u32> {
+        match self {
+            ColorPreset::Temperature4000K => Some(4000),
+            ColorPreset::Temperature5000K => Some(5000),
+            ColorPreset::Temperature6500K => Some(6500),
+            ColorPreset::Temperature7500K => Some(7500),
+            ColorPreset::Temperature8200K => Some(8200),
+            ColorPreset::Temperature9300K => Some(9300),
+            ColorPreset::Temperature10000K => Some(10000),
+            ColorPreset::Temperature11500K => Some(11500),
+            _ => None,
+        }
+    }
+}
+
+impl From<u32> for ColorPreset {
+    fn from(value: u32) -> Self {
+        let mask = 0xff;
+        match value & mask {
+            0x01 => Self::Srgb,
+            0x02 => Self::DisplayNative,
+            0x03 => Self::Temperature4000K,
+            0x04 => Self::Temperature5000K,
+            0x05 => Self::Temperature6500K,
+            0x06 => Self::Temperature7500K,
+            0x07 => Self::Temperature8200K,
+            0x08 => Self::Temperature9300K,
+            0x09 => Self::Temperature10000K,
+            0x0A => Self::Temperature11500K,
+            0x0B => Self::User1,
+            0x0C => Self::User2,
+            0x0D => Self::User3,
+            _ => Self::Reserved(value & mask),
+        }
+    }
+}
+
+impl From<ColorPreset> for u32 {
+    fn from(value: ColorPreset) -> Self {
+        match value {
+            ColorPreset::Srgb => 0x01,
+            ColorPreset::DisplayNative => 0x02,
+            ColorPreset::Temperature4000K => 0x03,
+            ColorPreset::Temperature5000K => 0x04,
+            ColorPreset::Temperature6500K => 0x05,
+            ColorPreset::Temperature7500K => 0x06,
+            ColorPreset::Temperature8200K => 0x07,
+            ColorPreset::Temperature9300K => 0x08,
+            ColorPreset::Temperature10000K => 0x09,
+            ColorPreset::Temperature11500K => 0x0A,
+            ColorPreset::User1 => 0x0B,
+            ColorPreset::User2 => 0x0C,
+            ColorPreset::User3 => 0x0D,
+            ColorPreset::Reserved(value) => value,
+        }
+    }
+}
+
+impl VcpValue for ColorPreset {
+    fn vcp_feature() -> VcpFeatureCode {
+        VcpFeatureCode::SelectColorPreset
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PowerMode {
+    On,
+    Standby,
+    Suspend,
+    Off,
+    /// display has been turned off, equivalent to pressing the power button
+    PowerOffButton,
+    Reserved(u32),
+}
+
+impl From<u32> for PowerMode {
+    fn from(value: u32) -> Self {
+        let mask = 0xff;
+        match value & mask {
+            0x01 => Self::On,
+            0x02 => Self::Standby,
+            0x03 => Self::Suspend,
+            0x04 => Self::Off,
+            0x05 => Self::PowerOffButton,
+            _ => Self::Reserved(value & mask),
+        }
+    }
+}
+
+impl From<PowerMode> for u32 {
+    fn from(value: PowerMode) -> Self {
+        match value {
+            PowerMode::On => 0x01,
+            PowerMode::Standby => 0x02,
+            PowerMode::Suspend => 0x03,
+            PowerMode::Off => 0x04,
+            PowerMode::PowerOffButton => 0x05,
+            PowerMode::Reserved(value) => value,
+        }
+    }
+}
+
+impl VcpValue for PowerMode {
+    fn vcp_feature() -> VcpFeatureCode {
+        VcpFeatureCode::PowerMode
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AudioMute {
+    Muted,
+    Unmuted,
+    Reserved(u32),
+}
+
+impl From<u32> for AudioMute {
+    fn from(value: u32) -> Self {
+        let mask = 0xff;
+        match value & mask {
+            0x01 => Self::Muted,
+            0x02 => Self::Unmuted,
+            _ => Self::Reserved(value & mask),
+        }
+    }
+}
+
+impl From<AudioMute> for u32 {
+    fn from(value: AudioMute) -> Self {
+        match value {
+            AudioMute::Muted => 0x01,
+            AudioMute::Unmuted => 0x02,
+            AudioMute::Reserved(value) => value,
+        }
+    }
+}
+
+impl VcpValue for AudioMute {
+    fn vcp_feature() -> VcpFeatureCode {
+        VcpFeatureCode::AudioMuteScreenBlank
+    }
+}
+
+/// Audio processor modes, surround modes carry the channel configuration in the range of
+/// 0x01 (2.0 channels) to 0x07 (7.1 channels)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AudioProcessorMode {
+    SpeakerOff,
+    Mono,
+    Stereo,
+    StereoExpanded,
+    Srs(u8),
+    Dolby(u8),
+    Thx(u8),
+    Reserved(u32),
+}
+
+impl AudioProcessorMode {
+    /// channel configuration of surround modes e.g. "5.1"
+    pub fn channel_layout(&self) -> Option<&'static str> {
+        let layouts = ["2.0", "2.1", "3.1", "4.1", "5.1", "6.1", "7.1"];
+        match self {
+            Self::Srs(channels) | Self::Dolby(channels) | Self::Thx(channels) => {
+                layouts.get((*channels as usize).checked_sub(1)?).copied()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<u32> for AudioProcessorMode {
+    fn from(value: u32) -> Self {
+        let mask = 0xff;
+        match value & mask {
+            0x00 => Self::SpeakerOff,
+            0x01 => Self::Mono,
+            0x02 => Self::Stereo,
+            0x03 => Self::StereoExpanded,
+            0x11..=0x17 => Self::Srs((value & 0x0f) as u8),
+            0x21..=0x27 => Self::Dolby((value & 0x0f) as u8),
+            0x31..=0x37 => Self::Thx((value & 0x0f) as u8),
+            _ => Self::Reserved(value & mask),
+        }
+    }
+}
+
+impl From<AudioProcessorMode> for u32 {
+    fn from(value: AudioProcessorMode) -> Self {
+        match value {
+            AudioProcessorMode::SpeakerOff => 0x00,
+            AudioProcessorMode::Mono => 0x01,
+            AudioProcessorMode::Stereo => 0x02,
+            AudioProcessorMode::StereoExpanded => 0x03,
+            AudioProcessorMode::Srs(channels) => 0x10 | (channels & 0x0f) as u32,
+            AudioProcessorMode::Dolby(channels) => 0x20 | (channels & 0x0f) as u32,
+            AudioProcessorMode::Thx(channels) => 0x30 | (channels & 0x0f) as u32,
+            AudioProcessorMode::Reserved(value) => value,
+        }
+    }
+}
+
+impl VcpValue for AudioProcessorMode {
+    fn vcp_feature() -> VcpFeatureCode {
+        VcpFeatureCode::AudioProcessorMode
+    }
+}
+
+/// Accumulated usage time of the display, as reported by the display
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayUsageTimeValue(u32);
+
+impl DisplayUsageTimeValue {
+    /// usage time in hours, starting with MCCS 3.0 the value is extended to 24 bit
+    pub fn hours(&self, version: &Version) -> u32 {
+        if version.major >= 3 {
+            self.0 & 0x00ff_ffff
+        } else {
+            self.0 & 0xffff
+        }
+    }
+}
+
+impl From<u32> for DisplayUsageTimeValue {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DisplayUsageTimeValue> for u32 {
+    fn from(value: DisplayUsageTimeValue) -> Self {
+        value.0
+    }
+}
+
+impl VcpValue for DisplayUsageTimeValue {
+    fn vcp_feature() -> VcpFeatureCode {
+        VcpFeatureCode::DisplayUsageTime
+    }
+}
+
+/// Manufacturer of the display controller
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ControllerManufacturer {
+    Conexant,
+    Genesis,
+    Macronix,
+    Idt,
+    Mstar,
+    Myson,
+    Philips,
+    PixelWorks,
+    RealTek,
+    Sage,
+    SiliconImage,
+    SmartAsic,
+    StMicroelectronics,
+    Topro,
+    Trumpion,
+    Welltrend,
+    Samsung,
+    Novatek,
+    Stk,
+    SiliconOptix,
+    TexasInstruments,
+    Analogix,
+    QuantumData,
+    Nxp,
+    Chrontel,
+    Parade,
+    THine,
+    Trident,
+    Micronas,
+    NotDefined,
+    Reserved(u8),
+}
+
+impl From<u8> for ControllerManufacturer {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::Conexant,
+            0x02 => Self::Genesis,
+            0x03 => Self::Macronix,
+            0x04 => Self::Idt,
+            0x05 => Self::Mstar,
+            0x06 => Self::Myson,
+            0x07 => Self::Philips,
+            0x08 => Self::PixelWorks,
+            0x09 => Self::RealTek,
+            0x0a => Self::Sage,
+            0x0b => Self::SiliconImage,
+            0x0c => Self::SmartAsic,
+            0x0d => Self::StMicroelectronics,
+            0x0e => Self::Topro,
+            0x0f => Self::Trumpion,
+            0x10 => Self::Welltrend,
+            0x11 => Self::Samsung,
+            0x12 => Self::Novatek,
+            0x13 => Self::Stk,
+            0x14 => Self::SiliconOptix,
+            0x15 => Self::TexasInstruments,
+            0x16 => Self::Analogix,
+            0x17 => Self::QuantumData,
+            0x18 => Self::Nxp,
+            0x19 => Self::Chrontel,
+            0x1a => Self::Parade,
+            0x1b => Self::THine,
+            0x1c => Self::Trident,
+            0x1d => Self::Micronas,
+            0xff => Self::NotDefined,
+            _ => Self::Reserved(value),
+        }
+    }
+}
+
+impl From<ControllerManufacturer> for u8 {
+    fn from(value: ControllerManufacturer) -> Self {
+        match value {
+            ControllerManufacturer::Conexant => 0x01,
+            ControllerManufacturer::Genesis => 0x02,
+            ControllerManufacturer::Macronix => 0x03,
+            ControllerManufacturer::Idt => 0x04,
+            ControllerManufacturer::Mstar => 0x05,
+            ControllerManufacturer::Myson => 0x06,
+            ControllerManufacturer::Philips => 0x07,
+            ControllerManufacturer::PixelWorks => 0x08,
+            ControllerManufacturer::RealTek => 0x09,
+            ControllerManufacturer::Sage => 0x0a,
+            ControllerManufacturer::SiliconImage => 0x0b,
+            ControllerManufacturer::SmartAsic => 0x0c,
+            ControllerManufacturer::StMicroelectronics => 0x0d,
+            ControllerManufacturer::Topro => 0x0e,
+            ControllerManufacturer::Trumpion => 0x0f,
+            ControllerManufacturer::Welltrend => 0x10,
+            ControllerManufacturer::Samsung => 0x11,
+            ControllerManufacturer::Novatek => 0x12,
+            ControllerManufacturer::Stk => 0x13,
+            ControllerManufacturer::SiliconOptix => 0x14,
+            ControllerManufacturer::TexasInstruments => 0x15,
+            ControllerManufacturer::Analogix => 0x16,
+            ControllerManufacturer::QuantumData => 0x17,
+            ControllerManufacturer::Nxp => 0x18,
+            ControllerManufacturer::Chrontel => 0x19,
+            ControllerManufacturer::Parade => 0x1a,
+            ControllerManufacturer::THine => 0x1b,
+            ControllerManufacturer::Trident => 0x1c,
+            ControllerManufacturer::Micronas => 0x1d,
+            ControllerManufacturer::NotDefined => 0xff,
+            ControllerManufacturer::Reserved(value) => value,
+        }
+    }
+}
+
+/// Display controller type, the controller number is specific to the manufacturer
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DisplayControllerTypeValue {
+    pub manufacturer: ControllerManufacturer,
+    /// 24 bit controller number made up of the MH, ML and SH bytes
+    pub controller: u32,
+}
+
+impl From<u32> for DisplayControllerTypeValue {
+    fn from(value: u32) -> Self {
+        Self {
+            manufacturer: ((value & 0xff) as u8).into(),
+            controller: value >> 8,
+        }
+    }
+}
+
+impl From<DisplayControllerTypeValue> for u32 {
+    fn from(value: DisplayControllerTypeValue) -> Self {
+        (value.controller & 0xffffff) << 8 | u8::from(value.manufacturer) as u32
+    }
+}
+
+impl VcpValue for DisplayControllerTypeValue {
+    fn vcp_feature() -> VcpFeatureCode {
+        VcpFeatureCode::DisplayControllerType
+    }
+}
+
+/// Firmware level of the display controller
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DisplayFirmwareLevelValue {
+    pub version: u8,
+    pub revision: u8,
+}
+
+impl Display for DisplayFirmwareLevelValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.version, self.revision)
+    }
+}
+
+impl From<u32> for DisplayFirmwareLevelValue {
+    fn from(value: u32) -> Self {
+        Self {
+            version: (value >> 8 & 0xff) as u8,
+            revision: (value & 0xff) as u8,
+        }
+    }
+}
+
+impl From<DisplayFirmwareLevelValue> for u32 {
+    fn from(value: DisplayFirmwareLevelValue) -> Self {
+        (value.version as u32) << 8 | value.revision as u32
+    }
+}
+
+impl VcpValue for DisplayFirmwareLevelValue {
+    fn vcp_feature() -> VcpFeatureCode {
+        VcpFeatureCode::DisplayFirmwareLevel
+    }
+}
+
+/// MCCS version implemented by the display
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VcpVersionValue(pub Version);
+
+impl From<u32> for VcpVersionValue {
+    fn from(value: u32) -> Self {
+        Self(Version::new(
+            (value >> 8 & 0xff) as u8,
+            (value & 0xff) as u8,
+        ))
+    }
+}
+
+impl From<VcpVersionValue> for u32 {
+    fn from(value: VcpVersionValue) -> Self {
+        (value.0.major as u32) << 8 | value.0.minor as u32
+    }
+}
+
+impl VcpValue for VcpVersionValue {
+    fn vcp_feature() -> VcpFeatureCode {
+        VcpFeatureCode::VcpVersion
+    }
+}
+
+/// Settings that can be restored to their factory defaults
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RestoreDefaults {
+    /// restore all factory defaults
+    Factory,
+    LuminanceContrast,
+    Geometry,
+    Color,
+    TvDefaults,
+}
+
+impl RestoreDefaults {
+    pub fn vcp_feature(&self) -> VcpFeatureCode {
+        match self {
+            RestoreDefaults::Factory => VcpFeatureCode::RestoreFactoryDefaults,
+            RestoreDefaults::LuminanceContrast => VcpFeatureCode::RestoreFactoryLuminanceContrast,
+            RestoreDefaults::Geometry => VcpFeatureCode::RestoreFactoryGeometry,
+            RestoreDefaults::Color => VcpFeatureCode::RestoreFactoryColor,
+            RestoreDefaults::TvDefaults => VcpFeatureCode::RestoreFactoryTvDefaults,
+        }
+    }
+}
+
+/// Explicit confirmation that is required to restore factory defaults.
+///
+/// Restore operations can not be sent using the generic vcp feature setters, so the user settings
+/// of a monitor can not be lost by accident.
+#[derive(Debug)]
+pub struct ConfirmRestore(());
+
+impl ConfirmRestore {
+    /// confirm that the monitor settings will be lost by the restore operation
+    pub fn confirmed() -> Self {
+        Self(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ControllerManufacturer, DisplayControllerTypeValue};
+
+    #[test]
+    fn display_controller_type() {
+        // MH ML SH SL, the manufacturer is stored in SL
+        let controller = DisplayControllerTypeValue::from(0x0012_3405);
+        assert_eq!(controller.manufacturer, ControllerManufacturer::Mstar);
+        assert_eq!(controller.controller, 0x1234);
+        assert_eq!(u32::from(controller), 0x0012_3405);
+
+        let controller = DisplayControllerTypeValue::from(0xab12_3402);
+        assert_eq!(controller.manufacturer, ControllerManufacturer::Genesis);
+        assert_eq!(controller.controller, 0xab1234);
+        assert_eq!(u32::from(controller), 0xab12_3402);
+    }
+}
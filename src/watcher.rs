@@ -0,0 +1,361 @@
+//! Hotplug monitoring of displays using udev events
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+    os::fd::AsRawFd,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use udev::{MonitorBuilder, MonitorSocket};
+
+use crate::{
+    ddc::{linux::LinuxDdcDeviceEnumerator, DdcDevice},
+    DisplayError, Monitor, MonitorInfo,
+};
+
+/// time without further udev events before the connected displays are scanned again
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Change of the connected displays
+#[derive(Debug)]
+pub enum MonitorEvent {
+    /// a display has been connected
+    Added(Monitor),
+    /// a display has been disconnected
+    Removed {
+        /// connector name of the display, e.g. "DP-1"
+        name: String,
+        info: MonitorInfo,
+    },
+    /// a different display has been connected to a connector
+    Changed(Monitor),
+}
+
+#[derive(Debug)]
+struct ConnectedMonitor {
+    name: String,
+    info: MonitorInfo,
+}
+
+/// connector found while scanning, `monitor` is `None` if the display could not be opened
+struct ScannedConnector<T> {
+    connector: PathBuf,
+    name: String,
+    /// drm connector status, the display has been unplugged if the connector is disconnected
+    connected: bool,
+    monitor: Option<(MonitorInfo, T)>,
+}
+
+// outside of tests the variants hold a `Monitor` of similar size
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+enum Change<T> {
+    Added(T),
+    Changed(T),
+    Removed(ConnectedMonitor),
+}
+
+/// compare the connectors of a scan to the previously connected displays
+fn diff_monitors<T>(
+    connected: &mut HashMap<PathBuf, ConnectedMonitor>,
+    scanned: Vec<ScannedConnector<T>>,
+) -> Vec<Change<T>> {
+    let mut previous = std::mem::take(connected);
+    let mut changes = Vec::new();
+    for ScannedConnector {
+        connector,
+        name,
+        connected: connector_connected,
+        monitor,
+    } in scanned
+    {
+        // the i2c bus of a connector outlives the display, a disconnected connector removes the
+        // previous entry
+        if !connector_connected {
+            continue;
+        }
+        match (previous.remove(&connector), monitor) {
+            // displays may not provide their edid until they finished powering up or fail to
+            // respond temporarily, the previous entry is kept while the connector is connected
+            (Some(entry), None) => {
+                connected.insert(connector, entry);
+            }
+            (None, None) => {}
+            (None, Some((info, monitor))) => {
+                connected.insert(connector, ConnectedMonitor { name, info });
+                changes.push(Change::Added(monitor));
+            }
+            (Some(entry), Some((info, monitor))) if entry.info.edid() != info.edid() => {
+                connected.insert(connector, ConnectedMonitor { name, info });
+                changes.push(Change::Changed(monitor));
+            }
+            (Some(entry), Some(_)) => {
+                connected.insert(connector, entry);
+            }
+        }
+    }
+    changes.extend(previous.into_values().map(Change::Removed));
+    changes
+}
+
+/// delays scanning until no further udev events arrived for the debounce duration
+struct Debounce {
+    duration: Duration,
+    last_event: Option<Instant>,
+}
+
+impl Debounce {
+    fn event(&mut self, now: Instant) {
+        self.last_event = Some(now);
+    }
+
+    /// time until a scan is due, `None` if no events are pending
+    fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.last_event
+            .map(|last_event| self.duration.saturating_sub(now - last_event))
+    }
+
+    /// check if a scan is due and reset the pending events
+    fn take_due(&mut self, now: Instant) -> bool {
+        let due = self.remaining(now) == Some(Duration::ZERO);
+        if due {
+            self.last_event = None;
+        }
+        due
+    }
+}
+
+/// block until the file descriptor is readable or the timeout elapsed, `None` waits indefinitely
+fn wait_readable(fd: &impl AsRawFd, timeout: Option<Duration>) -> io::Result<()> {
+    let mut pollfd = libc::pollfd {
+        fd: fd.as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let timeout = timeout.map_or(-1, |timeout| {
+        timeout
+            .as_nanos()
+            .div_ceil(1_000_000)
+            .min(libc::c_int::MAX as u128) as libc::c_int
+    });
+    // SAFETY: pollfd is a single valid entry for the duration of the call
+    let result = unsafe { libc::poll(&mut pollfd, 1, timeout) };
+    if result < 0 {
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+/// Watch for displays being connected or disconnected
+///
+/// Events of the `drm` and `i2c-dev` subsystems trigger a scan of all connected displays once no
+/// further events arrived for the debounce duration. This collapses the burst of events a docking
+/// station produces into a single change per display. Displays connected while creating the
+/// watcher are not reported.
+///
+/// ```rust,no_run
+/// use libmonitor::{MonitorEvent, MonitorWatcher};
+///
+/// for event in MonitorWatcher::new().unwrap() {
+///     if let MonitorEvent::Added(mut monitor) = event {
+///         let _ = monitor.set_luminance(0.5);
+///     }
+/// }
+/// ```
+pub struct MonitorWatcher {
+    socket: MonitorSocket,
+    debounce: Debounce,
+    connected: HashMap<PathBuf, ConnectedMonitor>,
+    pending: VecDeque<MonitorEvent>,
+}
+
+impl MonitorWatcher {
+    pub fn new() -> Result<Self, DisplayError> {
+        let socket = MonitorBuilder::new()?
+            .match_subsystem("drm")?
+            .match_subsystem("i2c-dev")?
+            .listen()?;
+        let mut watcher = Self {
+            socket,
+            debounce: Debounce {
+                duration: DEFAULT_DEBOUNCE,
+                last_event: None,
+            },
+            connected: HashMap::new(),
+            pending: VecDeque::new(),
+        };
+        // only changes after creating the watcher are reported
+        let _ = watcher.rescan();
+        Ok(watcher)
+    }
+
+    /// set the time without udev events after which displays are scanned
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce.duration = debounce;
+        self
+    }
+
+    /// check for display changes without blocking
+    pub fn poll(&mut self) -> Vec<MonitorEvent> {
+        let now = Instant::now();
+        if self.socket.iter().count() > 0 {
+            self.debounce.event(now);
+        }
+        if self.debounce.take_due(now) {
+            self.rescan()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// compare the connected displays to the previous scan
+    fn rescan(&mut self) -> Vec<MonitorEvent> {
+        let scanned = LinuxDdcDeviceEnumerator::iter()
+            .map(|device| {
+                let connector = device.drm_syspath().to_path_buf();
+                let name = device.name();
+                let connected = device.connected();
+                let monitor = Monitor::new(device)
+                    .ok()
+                    .map(|monitor| (monitor.info.clone(), monitor));
+                ScannedConnector {
+                    connector,
+                    name,
+                    connected,
+                    monitor,
+                }
+            })
+            .collect();
+        diff_monitors(&mut self.connected, scanned)
+            .into_iter()
+            .map(|change| match change {
+                Change::Added(monitor) => MonitorEvent::Added(monitor),
+                Change::Changed(monitor) => MonitorEvent::Changed(monitor),
+                Change::Removed(previous) => MonitorEvent::Removed {
+                    name: previous.name,
+                    info: previous.info,
+                },
+            })
+            .collect()
+    }
+}
+
+impl Iterator for MonitorWatcher {
+    type Item = MonitorEvent;
+
+    /// block until the next display change, ends if the udev socket can not be polled
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(event);
+            }
+            let timeout = self.debounce.remaining(Instant::now());
+            if let Err(err) = wait_readable(&self.socket, timeout) {
+                log::error!("polling udev events failed: {err}");
+                return None;
+            }
+            let events = self.poll();
+            self.pending.extend(events);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashMap,
+        path::PathBuf,
+        time::{Duration, Instant},
+    };
+
+    use super::{diff_monitors, Change, ConnectedMonitor, Debounce, ScannedConnector};
+    use crate::{ddc::edid::EdidBuilder, MonitorId, MonitorInfo};
+
+    fn info(product: u16) -> MonitorInfo {
+        let edid = EdidBuilder::new().product(product).build().unwrap();
+        MonitorInfo {
+            id: MonitorId::new(&edid, None),
+            edid_data: edid.to_bytes(),
+            edid,
+            mccs_features: None,
+            firmware: None,
+        }
+    }
+
+    fn scanned(connector: &str, product: Option<u16>) -> ScannedConnector<&str> {
+        ScannedConnector {
+            connector: PathBuf::from(connector),
+            name: connector.to_string(),
+            connected: true,
+            monitor: product.map(|product| (info(product), connector)),
+        }
+    }
+
+    #[test]
+    fn diff_connected_monitors() {
+        let mut connected: HashMap<PathBuf, ConnectedMonitor> = HashMap::new();
+        let changes = diff_monitors(
+            &mut connected,
+            vec![scanned("DP-1", Some(1)), scanned("DP-2", Some(2))],
+        );
+        assert!(matches!(changes[..], [Change::Added(_), Change::Added(_)]));
+        assert_eq!(connected.len(), 2);
+
+        // unchanged displays and connectors whose display failed to open produce no events
+        let changes = diff_monitors(
+            &mut connected,
+            vec![scanned("DP-1", Some(1)), scanned("DP-2", None)],
+        );
+        assert!(changes.is_empty());
+        assert_eq!(connected.len(), 2);
+
+        let changes = diff_monitors(&mut connected, vec![scanned("DP-1", Some(3))]);
+        assert!(matches!(
+            &changes[..],
+            [Change::Changed("DP-1"), Change::Removed(ConnectedMonitor { name, .. })]
+                if name == "DP-2"
+        ));
+        assert_eq!(
+            connected[&PathBuf::from("DP-1")].info.edid().header.product,
+            3
+        );
+
+        // a disconnected connector removes the display even though its bus is still present
+        let disconnected = ScannedConnector {
+            connected: false,
+            ..scanned("DP-1", None)
+        };
+        let changes = diff_monitors(&mut connected, vec![disconnected]);
+        assert!(matches!(
+            &changes[..],
+            [Change::Removed(ConnectedMonitor { name, .. })] if name == "DP-1"
+        ));
+        assert!(connected.is_empty());
+    }
+
+    #[test]
+    fn debounce_events() {
+        let start = Instant::now();
+        let mut debounce = Debounce {
+            duration: Duration::from_millis(500),
+            last_event: None,
+        };
+        assert_eq!(debounce.remaining(start), None);
+        assert!(!debounce.take_due(start));
+
+        debounce.event(start);
+        debounce.event(start + Duration::from_millis(300));
+        let now = start + Duration::from_millis(600);
+        assert_eq!(debounce.remaining(now), Some(Duration::from_millis(200)));
+        assert!(!debounce.take_due(now));
+
+        let now = start + Duration::from_millis(800);
+        assert!(debounce.take_due(now));
+        assert_eq!(debounce.remaining(now), None);
+    }
+}